@@ -0,0 +1,158 @@
+// Corpus-level aggregation for profiling a stream of safety analyses.
+//
+// Every `analyze` call is independent, so on its own it says nothing about how
+// a population of inputs behaves. This module accumulates coalesceable,
+// serializable state across many analyses — which violation types fire most,
+// representative evidence per category, and the range of scores and text
+// lengths — giving operators a dashboard/drift-detection summary without
+// retaining whole inputs. Privacy evidence arrives already redacted; other
+// categories' evidence is the matched span quoted from the input.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+
+use crate::safety::SafetyResult;
+
+/// The global profile fed by the FFI entry points.
+static SAFETY_PROFILE: Lazy<Mutex<SafetyProfile>> = Lazy::new(|| Mutex::new(SafetyProfile::new()));
+
+/// A count per key, e.g. how often each violation type has been seen.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct CountingSet {
+    counts: HashMap<String, u64>,
+}
+
+impl CountingSet {
+    fn add(&mut self, key: &str) {
+        *self.counts.entry(key.to_string()).or_insert(0) += 1;
+    }
+}
+
+/// A fixed-capacity reservoir sample, keeping a uniform random subset of the
+/// items offered to it without retaining the whole stream.
+#[derive(Debug, Clone, Serialize)]
+pub struct Sampler {
+    samples: Vec<String>,
+    #[serde(skip)]
+    capacity: usize,
+    #[serde(skip)]
+    seen: u64,
+    #[serde(skip)]
+    state: u64,
+}
+
+impl Sampler {
+    fn new(capacity: usize) -> Self {
+        Self {
+            samples: Vec::with_capacity(capacity),
+            capacity,
+            seen: 0,
+            state: 0x2545_F491_4F6C_DD1D,
+        }
+    }
+
+    // xorshift64 — a cheap, deterministic PRNG for reservoir replacement.
+    fn next_rand(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    fn offer(&mut self, item: String) {
+        self.seen += 1;
+        if self.samples.len() < self.capacity {
+            self.samples.push(item);
+        } else {
+            let j = (self.next_rand() % self.seen) as usize;
+            if j < self.capacity {
+                self.samples[j] = item;
+            }
+        }
+    }
+}
+
+/// Running min/max (and count/sum for a mean) over a stream of values.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct MinMax {
+    min: Option<f64>,
+    max: Option<f64>,
+    count: u64,
+    sum: f64,
+}
+
+impl MinMax {
+    fn observe(&mut self, value: f64) {
+        self.min = Some(self.min.map_or(value, |m| m.min(value)));
+        self.max = Some(self.max.map_or(value, |m| m.max(value)));
+        self.count += 1;
+        self.sum += value;
+    }
+}
+
+const EVIDENCE_SAMPLE_CAPACITY: usize = 16;
+
+/// The accumulated corpus profile. Serializes directly to the snapshot JSON.
+#[derive(Debug, Clone, Serialize)]
+pub struct SafetyProfile {
+    analyses: u64,
+    total_pattern_matches: u64,
+    violation_types: CountingSet,
+    evidence_samples: HashMap<String, Sampler>,
+    score: MinMax,
+    text_length: MinMax,
+}
+
+impl SafetyProfile {
+    fn new() -> Self {
+        Self {
+            analyses: 0,
+            total_pattern_matches: 0,
+            violation_types: CountingSet::default(),
+            evidence_samples: HashMap::new(),
+            score: MinMax::default(),
+            text_length: MinMax::default(),
+        }
+    }
+
+    /// Fold a single analysis result into the profile.
+    pub fn record(&mut self, result: &SafetyResult) {
+        self.analyses += 1;
+        self.total_pattern_matches += result.metadata.pattern_matches as u64;
+        self.score.observe(result.score);
+        self.text_length.observe(result.metadata.text_length as f64);
+
+        for violation in &result.violations {
+            self.violation_types.add(&violation.r#type);
+            let sampler = self
+                .evidence_samples
+                .entry(violation.r#type.clone())
+                .or_insert_with(|| Sampler::new(EVIDENCE_SAMPLE_CAPACITY));
+            for evidence in &violation.evidence {
+                sampler.offer(evidence.clone());
+            }
+        }
+    }
+}
+
+/// Fold a result into the global profile. Called from the analysis FFI.
+pub fn record(result: &SafetyResult) {
+    if let Ok(mut profile) = SAFETY_PROFILE.lock() {
+        profile.record(result);
+    }
+}
+
+/// Serialize the current aggregate profile as JSON.
+pub fn snapshot_json() -> Option<String> {
+    let profile = SAFETY_PROFILE.lock().ok()?;
+    serde_json::to_string(&*profile).ok()
+}
+
+/// Clear all accumulated state.
+pub fn reset() {
+    if let Ok(mut profile) = SAFETY_PROFILE.lock() {
+        *profile = SafetyProfile::new();
+    }
+}
@@ -76,6 +76,7 @@ pub trait ToxicityAnalyzer {
 
 // Advanced struct definitions with comprehensive data
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SafetyScore {
     pub overall_score: f64,
     pub confidence: f64,
@@ -85,7 +86,25 @@ pub struct SafetyScore {
     pub metadata: AnalysisMetadata,
 }
 
+#[cfg(feature = "serde")]
+impl SafetyScore {
+    /// Serialize this score to a JSON string, mapping failures to
+    /// [`SafetyAnalysisError::SerializationError`].
+    pub fn to_json(&self) -> SafetyResult<String> {
+        serde_json::to_string(self)
+            .map_err(|e| SafetyAnalysisError::SerializationError(e.to_string()))
+    }
+
+    /// Parse a score from a JSON string, mapping failures to
+    /// [`SafetyAnalysisError::SerializationError`].
+    pub fn from_json(json: &str) -> SafetyResult<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| SafetyAnalysisError::SerializationError(e.to_string()))
+    }
+}
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CategoryScore {
     pub score: f64,
     pub confidence: f64,
@@ -95,6 +114,7 @@ pub struct CategoryScore {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SafetyFlag {
     pub flag_type: FlagType,
     pub severity: Severity,
@@ -105,6 +125,7 @@ pub struct SafetyFlag {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FlagType {
     ContentViolation,
     BiasDetected,
@@ -115,6 +136,7 @@ pub enum FlagType {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Severity {
     Low,
     Medium,
@@ -123,6 +145,7 @@ pub enum Severity {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextLocation {
     pub start: usize,
     pub end: usize,
@@ -131,6 +154,7 @@ pub struct TextLocation {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnalysisMetadata {
     pub analyzer_version: String,
     pub model_versions: HashMap<String, String>,
@@ -140,6 +164,7 @@ pub struct AnalysisMetadata {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SystemInfo {
     pub cpu_cores: usize,
     pub memory_mb: usize,
@@ -148,6 +173,7 @@ pub struct SystemInfo {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstitutionalPrinciple {
     pub id: String,
     pub name: String,
@@ -157,7 +183,8 @@ pub struct ConstitutionalPrinciple {
     pub enforcement_level: EnforcementLevel,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PrincipleCategory {
     Harmlessness,
     Helpfulness,
@@ -168,6 +195,7 @@ pub enum PrincipleCategory {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EnforcementLevel {
     Warning,
     Block,
@@ -176,6 +204,7 @@ pub enum EnforcementLevel {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PrincipleScore {
     pub principle_id: String,
     pub score: f64,
@@ -185,6 +214,7 @@ pub struct PrincipleScore {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Violation {
     pub location: TextLocation,
     pub severity: Severity,
@@ -193,6 +223,7 @@ pub struct Violation {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstitutionalAnalysis {
     pub overall_compliance: f64,
     pub principle_scores: Vec<PrincipleScore>,
@@ -201,6 +232,7 @@ pub struct ConstitutionalAnalysis {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BiasAnalysis {
     pub overall_bias_score: f64,
     pub bias_types: HashMap<BiasType, f64>,
@@ -209,6 +241,7 @@ pub struct BiasAnalysis {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BiasType {
     Gender,
     Racial,
@@ -221,6 +254,7 @@ pub enum BiasType {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BiasCategory {
     Implicit,
     Explicit,
@@ -229,6 +263,7 @@ pub enum BiasCategory {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BiasEvidence {
     pub bias_type: BiasType,
     pub confidence: f64,
@@ -238,6 +273,7 @@ pub struct BiasEvidence {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ToxicityScore {
     pub overall_toxicity: f64,
     pub toxicity_categories: HashMap<ToxicityCategory, f64>,
@@ -246,6 +282,7 @@ pub struct ToxicityScore {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ToxicityCategory {
     Harassment,
     Hate,
@@ -256,6 +293,7 @@ pub enum ToxicityCategory {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnalyzerInfo {
     pub name: String,
     pub version: String,
@@ -265,6 +303,7 @@ pub struct AnalyzerInfo {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PerformanceMetrics {
     pub avg_processing_time_ms: f64,
     pub throughput_per_second: f64,
@@ -277,7 +316,7 @@ pub struct PerformanceMetrics {
 // High-performance safety analyzer implementation
 pub struct AdvancedSafetyAnalyzer {
     models: Arc<RwLock<HashMap<String, Box<dyn AnalysisModel + Send + Sync>>>>,
-    cache: Arc<Mutex<HashMap<u64, SafetyScore>>>,
+    cache: Arc<Mutex<BoundedCache>>,
     config: AnalyzerConfig,
     thread_pool: Arc<ThreadPool>,
     metrics: Arc<Mutex<PerformanceMetrics>>,
@@ -290,6 +329,7 @@ pub trait AnalysisModel {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModelInfo {
     pub name: String,
     pub version: String,
@@ -305,6 +345,22 @@ pub struct AnalyzerConfig {
     pub memory_limit_mb: usize,
     pub enable_parallel_processing: bool,
     pub quality_threshold: f64,
+    /// Optional per-entry time-to-live; entries older than this are treated as
+    /// cache misses so stale model outputs expire. `None` disables expiry.
+    pub cache_ttl: Option<Duration>,
+    /// Maximum number of batch jobs queued on the pool at once. Bounds memory so
+    /// a huge batch doesn't allocate one pending closure per document.
+    pub max_in_flight: usize,
+    /// Per-category score thresholds, keyed by category name. Empty by default;
+    /// populated from a loaded configuration file.
+    pub category_thresholds: HashMap<String, f64>,
+    /// Path to an external sentiment lexicon, if overriding the embedded one.
+    pub sentiment_lexicon_path: Option<String>,
+    /// Path to an external blacklist term list for the term-matching analyzer.
+    pub blacklist_path: Option<String>,
+    /// Which `generate_analyzer!` family members are active, by lowercase name
+    /// (e.g. `"bias"`, `"toxicity"`, `"privacy"`).
+    pub active_analyzers: Vec<String>,
 }
 
 impl Default for AnalyzerConfig {
@@ -316,7 +372,215 @@ impl Default for AnalyzerConfig {
             memory_limit_mb: 512,
             enable_parallel_processing: true,
             quality_threshold: 0.85,
+            cache_ttl: None,
+            max_in_flight: 256,
+            category_thresholds: HashMap::new(),
+            sentiment_lexicon_path: None,
+            blacklist_path: None,
+            active_analyzers: vec![
+                "bias".to_string(),
+                "toxicity".to_string(),
+                "privacy".to_string(),
+            ],
+        }
+    }
+}
+
+/// TOML document schema for [`AnalyzerConfig::from_toml_str`]. Kept separate
+/// from the runtime config so the on-disk format can stay stable and TOML-native
+/// (durations in milliseconds, nested tables) while the in-memory type evolves.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct ConfigDocument {
+    #[serde(default)]
+    pub analyzer: AnalyzerSection,
+    #[serde(default)]
+    pub thresholds: HashMap<String, f64>,
+    #[serde(default)]
+    pub lexicons: LexiconSection,
+    #[serde(default)]
+    pub analyzers: AnalyzersSection,
+}
+
+/// The `[analyzer]` table: runtime tuning knobs. Every field is optional and
+/// falls back to [`AnalyzerConfig::default`].
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct AnalyzerSection {
+    pub cache_size: Option<usize>,
+    pub thread_count: Option<usize>,
+    pub timeout_ms: Option<u64>,
+    pub memory_limit_mb: Option<usize>,
+    pub enable_parallel_processing: Option<bool>,
+    pub quality_threshold: Option<f64>,
+    pub cache_ttl_ms: Option<u64>,
+    pub max_in_flight: Option<usize>,
+}
+
+/// The `[lexicons]` table: paths to external word lists.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct LexiconSection {
+    pub sentiment: Option<String>,
+    pub blacklist: Option<String>,
+}
+
+/// The `[analyzers]` table: which family members are enabled.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct AnalyzersSection {
+    pub active: Option<Vec<String>>,
+}
+
+#[cfg(feature = "serde")]
+impl AnalyzerConfig {
+    /// Load a configuration from a TOML file, layering it over the defaults.
+    pub fn from_toml_file(path: &str) -> SafetyResult<Self> {
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            SafetyAnalysisError::SerializationError(format!("{}: {}", path, e))
+        })?;
+        Self::from_toml_str(&contents)
+    }
+
+    /// Parse a TOML configuration string. Parse failures carry the line/column
+    /// context from the TOML parser so a misconfigured file is debuggable.
+    pub fn from_toml_str(contents: &str) -> SafetyResult<Self> {
+        let doc: ConfigDocument = toml::from_str(contents).map_err(|e| {
+            SafetyAnalysisError::SerializationError(format!("invalid config: {}", e))
+        })?;
+
+        let mut config = AnalyzerConfig::default();
+        let a = doc.analyzer;
+        if let Some(v) = a.cache_size {
+            config.cache_size = v;
+        }
+        if let Some(v) = a.thread_count {
+            config.thread_count = v;
+        }
+        if let Some(v) = a.timeout_ms {
+            config.timeout_ms = v;
+        }
+        if let Some(v) = a.memory_limit_mb {
+            config.memory_limit_mb = v;
+        }
+        if let Some(v) = a.enable_parallel_processing {
+            config.enable_parallel_processing = v;
+        }
+        if let Some(v) = a.quality_threshold {
+            config.quality_threshold = v;
+        }
+        if let Some(v) = a.cache_ttl_ms {
+            config.cache_ttl = Some(Duration::from_millis(v));
+        }
+        if let Some(v) = a.max_in_flight {
+            config.max_in_flight = v;
+        }
+
+        config.category_thresholds = doc.thresholds;
+        config.sentiment_lexicon_path = doc.lexicons.sentiment;
+        config.blacklist_path = doc.lexicons.blacklist;
+        if let Some(active) = doc.analyzers.active {
+            config.active_analyzers = active;
+        }
+
+        Ok(config)
+    }
+}
+
+/// Observable counters for tuning `cache_size`.
+#[derive(Debug, Clone, Default)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+}
+
+/// A bounded LRU cache with optional per-entry TTL that stores the original
+/// content next to each result. Lookups verify the stored content matches the
+/// query before returning a hit, so a `DefaultHasher` collision cannot surface
+/// another input's score.
+struct BoundedCache {
+    capacity: usize,
+    ttl: Option<Duration>,
+    entries: HashMap<u64, (String, SafetyScore, Instant)>,
+    order: VecDeque<u64>,
+    stats: CacheStats,
+}
+
+impl BoundedCache {
+    fn new(capacity: usize, ttl: Option<Duration>) -> Self {
+        Self {
+            capacity,
+            ttl,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// Fetch a cached score, verifying the stored content matches and the entry
+    /// has not expired. On a hit the key is promoted to most-recently-used.
+    fn get(&mut self, hash: u64, content: &str) -> Option<SafetyScore> {
+        let (collision, expired) = match self.entries.get(&hash) {
+            Some((stored_content, _, inserted)) => {
+                (stored_content != content, self.is_expired(*inserted))
+            }
+            None => {
+                self.stats.misses += 1;
+                return None;
+            }
+        };
+
+        if collision {
+            // A different input hashed to the same key: report a miss and leave
+            // the other input's live entry untouched.
+            self.stats.misses += 1;
+            return None;
+        }
+
+        if expired {
+            // Drop stale entries rather than returning a wrong hit.
+            self.entries.remove(&hash);
+            self.order.retain(|k| *k != hash);
+            self.stats.misses += 1;
+            return None;
+        }
+
+        self.touch(hash);
+        self.stats.hits += 1;
+        self.entries.get(&hash).map(|(_, score, _)| score.clone())
+    }
+
+    /// Insert a result, evicting the least-recently-used entry if inserting
+    /// would exceed the capacity.
+    fn insert(&mut self, hash: u64, content: String, score: SafetyScore, now: Instant) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.insert(hash, (content, score, now)).is_none() {
+            self.order.push_back(hash);
+        } else {
+            self.touch(hash);
+        }
+
+        while self.order.len() > self.capacity {
+            if let Some(evicted) = self.order.pop_front() {
+                self.entries.remove(&evicted);
+                self.stats.evictions += 1;
+            }
+        }
+    }
+
+    fn is_expired(&self, inserted: Instant) -> bool {
+        self.ttl.map_or(false, |ttl| inserted.elapsed() > ttl)
+    }
+
+    // Move `hash` to the back of the access order (most-recently-used).
+    fn touch(&mut self, hash: u64) {
+        if let Some(pos) = self.order.iter().position(|k| *k == hash) {
+            self.order.remove(pos);
         }
+        self.order.push_back(hash);
     }
 }
 
@@ -397,10 +661,11 @@ impl Worker {
 impl AdvancedSafetyAnalyzer {
     pub fn new(config: AnalyzerConfig) -> Self {
         let thread_pool = Arc::new(ThreadPool::new(config.thread_count));
-        
+        let cache = Arc::new(Mutex::new(BoundedCache::new(config.cache_size, config.cache_ttl)));
+
         Self {
             models: Arc::new(RwLock::new(HashMap::new())),
-            cache: Arc::new(Mutex::new(HashMap::new())),
+            cache,
             thread_pool,
             config,
             metrics: Arc::new(Mutex::new(PerformanceMetrics::default())),
@@ -419,10 +684,10 @@ impl AdvancedSafetyAnalyzer {
         // Create a hash for caching
         let content_hash = self.calculate_hash(content);
         
-        // Check cache first
-        if let Ok(cache) = self.cache.lock() {
-            if let Some(cached_result) = cache.get(&content_hash) {
-                return Ok(cached_result.clone());
+        // Check cache first (verifies stored content to guard against collisions)
+        if let Ok(mut cache) = self.cache.lock() {
+            if let Some(cached_result) = cache.get(content_hash, content) {
+                return Ok(cached_result);
             }
         }
 
@@ -442,7 +707,12 @@ impl AdvancedSafetyAnalyzer {
                 
                 // Update cache
                 if let Ok(mut cache) = self.cache.lock() {
-                    cache.insert(content_hash, analysis_result.clone());
+                    cache.insert(
+                        content_hash,
+                        content.to_string(),
+                        analysis_result.clone(),
+                        Instant::now(),
+                    );
                 }
 
                 // Update metrics
@@ -542,11 +812,38 @@ impl AdvancedSafetyAnalyzer {
     }
 
     fn calculate_hash(&self, content: &str) -> u64 {
+        Self::hash_content(content)
+    }
+
+    fn hash_content(content: &str) -> u64 {
         let mut hasher = DefaultHasher::new();
         content.hash(&mut hasher);
         hasher.finish()
     }
 
+    /// Cache-aware single-document analysis used by pooled batch jobs. Shares the
+    /// analyzer's `models` and `cache` through their `Arc`s so jobs don't build
+    /// throwaway analyzers.
+    fn perform_analysis_cached(
+        content: &str,
+        models: &Arc<RwLock<HashMap<String, Box<dyn AnalysisModel + Send + Sync>>>>,
+        cache: &Arc<Mutex<BoundedCache>>,
+    ) -> SafetyResult<SafetyScore> {
+        let hash = Self::hash_content(content);
+        if let Ok(mut guard) = cache.lock() {
+            if let Some(hit) = guard.get(hash, content) {
+                return Ok(hit);
+            }
+        }
+
+        let result = Self::perform_analysis(content, models)?;
+
+        if let Ok(mut guard) = cache.lock() {
+            guard.insert(hash, content.to_string(), result.clone(), Instant::now());
+        }
+        Ok(result)
+    }
+
     fn update_metrics(&self, duration: Duration) {
         if let Ok(mut metrics) = self.metrics.lock() {
             let duration_ms = duration.as_millis() as f64;
@@ -560,6 +857,15 @@ impl AdvancedSafetyAnalyzer {
             .map(|m| m.clone())
             .map_err(|_| SafetyAnalysisError::ConcurrencyError)
     }
+
+    /// Return the cache hit/miss/eviction counters so callers can tune
+    /// `cache_size` and `cache_ttl`.
+    pub fn cache_stats(&self) -> SafetyResult<CacheStats> {
+        self.cache
+            .lock()
+            .map(|c| c.stats.clone())
+            .map_err(|_| SafetyAnalysisError::ConcurrencyError)
+    }
 }
 
 impl Default for PerformanceMetrics {
@@ -612,42 +918,70 @@ impl SafetyAnalyzer for AdvancedSafetyAnalyzer {
 
 impl AdvancedSafetyAnalyzer {
     fn parallel_batch_analyze(&self, contents: &[&str]) -> SafetyResult<Vec<SafetyScore>> {
-        let (tx, rx) = std::sync::mpsc::channel();
-        let mut handles = Vec::new();
-
-        for (index, content) in contents.iter().enumerate() {
-            let content_owned = content.to_string();
-            let tx_clone = tx.clone();
-            let analyzer_config = self.config.clone();
-            let models_clone = Arc::clone(&self.models);
-
-            let handle = thread::spawn(move || {
-                let dummy_analyzer = AdvancedSafetyAnalyzer::new(analyzer_config);
-                dummy_analyzer.models = models_clone;
-                
-                let result = dummy_analyzer.analyze_content(&content_owned);
-                let _ = tx_clone.send((index, result));
-            });
-            handles.push(handle);
-        }
-
-        drop(tx); // Close the sending end
+        // Collapse the per-index results into the trait's `Vec`, surfacing the
+        // first item's own error rather than a blanket `ConcurrencyError`.
+        self.parallel_batch_analyze_indexed(contents)?
+            .into_iter()
+            .collect()
+    }
 
-        let mut results = vec![None; contents.len()];
-        for _ in 0..contents.len() {
-            if let Ok((index, result)) = rx.recv() {
-                results[index] = Some(result);
+    /// Run a batch on the shared `thread_pool`, returning one result per input
+    /// position so callers see partial successes alongside per-index errors.
+    ///
+    /// Jobs reuse the owned pool and share `models`/`cache` through their
+    /// `Arc`s; the driver updates `metrics` as each result arrives. At most `config.max_in_flight` jobs are queued at once: the
+    /// driver submits up to that many, then submits one more each time a result
+    /// arrives, so a huge batch never allocates one pending closure per item.
+    pub fn parallel_batch_analyze_indexed(
+        &self,
+        contents: &[&str],
+    ) -> SafetyResult<Vec<SafetyResult<SafetyScore>>> {
+        let total = contents.len();
+        let (tx, rx) = std::sync::mpsc::channel();
+        let max_in_flight = self.config.max_in_flight.max(1);
+
+        let mut results: Vec<Option<SafetyResult<SafetyScore>>> =
+            (0..total).map(|_| None).collect();
+        let mut next = 0;
+        let mut in_flight = 0usize;
+        let mut received = 0usize;
+
+        while received < total {
+            // Fill the in-flight window.
+            while in_flight < max_in_flight && next < total {
+                let index = next;
+                let content_owned = contents[index].to_string();
+                let tx_clone = tx.clone();
+                let models = Arc::clone(&self.models);
+                let cache = Arc::clone(&self.cache);
+
+                self.thread_pool.execute(move || {
+                    let result = Self::perform_analysis_cached(&content_owned, &models, &cache);
+                    let _ = tx_clone.send((index, result));
+                });
+
+                next += 1;
+                in_flight += 1;
             }
-        }
 
-        for handle in handles {
-            let _ = handle.join();
+            // Block for the next completed job.
+            match rx.recv() {
+                Ok((index, result)) => {
+                    self.update_metrics(Duration::from_millis(
+                        result.as_ref().map(|s| s.processing_time_ms).unwrap_or(0),
+                    ));
+                    results[index] = Some(result);
+                    in_flight -= 1;
+                    received += 1;
+                }
+                Err(_) => return Err(SafetyAnalysisError::ConcurrencyError),
+            }
         }
 
-        results
+        Ok(results
             .into_iter()
-            .collect::<Option<Result<Vec<_>, _>>>()
-            .ok_or(SafetyAnalysisError::ConcurrencyError)?
+            .map(|r| r.unwrap_or(Err(SafetyAnalysisError::ConcurrencyError)))
+            .collect())
     }
 
     fn sequential_batch_analyze(&self, contents: &[&str]) -> SafetyResult<Vec<SafetyScore>> {
@@ -656,12 +990,375 @@ impl AdvancedSafetyAnalyzer {
             .map(|content| self.analyze_content(content))
             .collect()
     }
+
+    /// Analyze a batch on the shared pool and write each successful result as
+    /// newline-delimited JSON to `writer` the moment its job completes, rather
+    /// than buffering the whole `Vec`. This lets the analyzer act as a pipeline
+    /// stage feeding structured findings to downstream tooling. Analysis errors
+    /// for individual documents are skipped; serde/IO failures abort with
+    /// [`SafetyAnalysisError::SerializationError`].
+    #[cfg(feature = "serde")]
+    pub fn stream_batch_analyze<W: std::io::Write>(
+        &self,
+        contents: &[&str],
+        writer: &mut W,
+    ) -> SafetyResult<()> {
+        let total = contents.len();
+        let (tx, rx) = std::sync::mpsc::channel();
+        let max_in_flight = self.config.max_in_flight.max(1);
+
+        let mut next = 0;
+        let mut in_flight = 0usize;
+        let mut received = 0usize;
+
+        while received < total {
+            while in_flight < max_in_flight && next < total {
+                let content_owned = contents[next].to_string();
+                let tx_clone = tx.clone();
+                let models = Arc::clone(&self.models);
+                let cache = Arc::clone(&self.cache);
+
+                self.thread_pool.execute(move || {
+                    let result = Self::perform_analysis_cached(&content_owned, &models, &cache);
+                    let _ = tx_clone.send(result);
+                });
+
+                next += 1;
+                in_flight += 1;
+            }
+
+            match rx.recv() {
+                Ok(result) => {
+                    in_flight -= 1;
+                    received += 1;
+                    if let Ok(score) = result {
+                        let line = score.to_json()?;
+                        writer
+                            .write_all(line.as_bytes())
+                            .and_then(|_| writer.write_all(b"\n"))
+                            .map_err(|e| {
+                                SafetyAnalysisError::SerializationError(e.to_string())
+                            })?;
+                    }
+                }
+                Err(_) => return Err(SafetyAnalysisError::ConcurrencyError),
+            }
+        }
+
+        Ok(())
+    }
 }
 
 // Specialized Constitutional AI implementation
+
+/// A single detection rule: forbidden keywords/patterns and required
+/// disclaimers, each producing a [`Violation`] with byte-offset locations.
+pub struct DetectionRule {
+    pub description: String,
+    pub severity: Severity,
+    pub forbidden_keywords: Vec<String>,
+    pub forbidden_patterns: Vec<regex::Regex>,
+    pub required_disclaimers: Vec<String>,
+    pub suggested_fix: Option<String>,
+}
+
+impl DetectionRule {
+    /// Scan `content` and emit one violation per match, with accurate byte
+    /// offsets. A missing required disclaimer is itself a violation.
+    fn find_violations(&self, content: &str) -> Vec<Violation> {
+        let mut violations = Vec::new();
+
+        for keyword in &self.forbidden_keywords {
+            for (start, end) in find_case_insensitive(content, keyword) {
+                violations.push(self.violation_at(start, end, format!("forbidden term: {}", keyword)));
+            }
+        }
+
+        for pattern in &self.forbidden_patterns {
+            for m in pattern.find_iter(content) {
+                violations.push(self.violation_at(
+                    m.start(),
+                    m.end(),
+                    format!("forbidden pattern: {}", pattern.as_str()),
+                ));
+            }
+        }
+
+        for disclaimer in &self.required_disclaimers {
+            if find_case_insensitive(content, disclaimer).is_empty() {
+                violations.push(self.violation_at(
+                    0,
+                    0,
+                    format!("missing required disclaimer: {}", disclaimer),
+                ));
+            }
+        }
+
+        violations
+    }
+
+    fn violation_at(&self, start: usize, end: usize, description: String) -> Violation {
+        Violation {
+            location: TextLocation {
+                start,
+                end,
+                line: None,
+                column: None,
+            },
+            severity: self.severity.clone(),
+            description,
+            suggested_fix: self.suggested_fix.clone(),
+        }
+    }
+}
+
+/// Raise a severity one level (saturating at `Critical`), for `Escalate`
+/// enforcement.
+fn escalate(severity: &Severity) -> Severity {
+    match severity {
+        Severity::Low => Severity::Medium,
+        Severity::Medium => Severity::High,
+        Severity::High | Severity::Critical => Severity::Critical,
+    }
+}
+
+/// Byte ranges of every case-insensitive occurrence of `needle`, reported
+/// against the original `haystack` so the offsets stay accurate even when
+/// lowercasing would change the byte length (e.g. `İ`, `ß`).
+fn find_case_insensitive(haystack: &str, needle: &str) -> Vec<(usize, usize)> {
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    let pattern = match regex::RegexBuilder::new(&regex::escape(needle))
+        .case_insensitive(true)
+        .build()
+    {
+        Ok(re) => re,
+        Err(_) => return Vec::new(),
+    };
+    pattern
+        .find_iter(haystack)
+        .map(|m| (m.start(), m.end()))
+        .collect()
+}
+
+/// Rule-based evaluator registered per [`PrincipleCategory`]. Each principle it
+/// owns carries a set of [`DetectionRule`]s; the principle score falls with the
+/// number of violations those rules report (one per match).
+pub struct RuleBasedEvaluator {
+    principles: Vec<(ConstitutionalPrinciple, Vec<DetectionRule>)>,
+}
+
+impl RuleBasedEvaluator {
+    pub fn new(principles: Vec<(ConstitutionalPrinciple, Vec<DetectionRule>)>) -> Self {
+        Self { principles }
+    }
+
+    fn rules_for(&self, principle_id: &str) -> Option<&[DetectionRule]> {
+        self.principles
+            .iter()
+            .find(|(p, _)| p.id == principle_id)
+            .map(|(_, rules)| rules.as_slice())
+    }
+}
+
+impl ConstitutionalPrincipleEvaluator for RuleBasedEvaluator {
+    fn evaluate_principle(
+        &self,
+        content: &str,
+        principle: &ConstitutionalPrinciple,
+    ) -> SafetyResult<PrincipleScore> {
+        let mut violations = Vec::new();
+        if let Some(rules) = self.rules_for(&principle.id) {
+            for rule in rules {
+                violations.extend(rule.find_violations(content));
+            }
+        }
+
+        // Each violation erodes the compliance score by 0.25.
+        let score = (1.0 - 0.25 * violations.len() as f64).max(0.0);
+        let explanation = if violations.is_empty() {
+            format!("No rule matched for principle: {}", principle.name)
+        } else {
+            format!(
+                "{} rule violation(s) for principle: {}",
+                violations.len(),
+                principle.name
+            )
+        };
+
+        Ok(PrincipleScore {
+            principle_id: principle.id.clone(),
+            score,
+            confidence: 0.9,
+            explanation,
+            violations,
+        })
+    }
+
+    fn apply_all_principles(&self, content: &str) -> SafetyResult<ConstitutionalAnalysis> {
+        let mut principle_scores = Vec::new();
+        let mut total_weighted = 0.0;
+        let mut total_weight = 0.0;
+        for (principle, _) in &self.principles {
+            let score = self.evaluate_principle(content, principle)?;
+            total_weighted += score.score * principle.weight;
+            total_weight += principle.weight;
+            principle_scores.push(score);
+        }
+        let overall_compliance = if total_weight > 0.0 {
+            total_weighted / total_weight
+        } else {
+            1.0
+        };
+        Ok(ConstitutionalAnalysis {
+            overall_compliance,
+            principle_scores,
+            recommendations: Vec::new(),
+            requires_human_review: overall_compliance < 0.7,
+        })
+    }
+}
+
+/// A user-configurable policy DSL for [`ConstitutionalAIAnalyzer`]. Rules are
+/// authored as boolean expressions over terms and quoted phrases — e.g.
+/// `medical_advice AND NOT "see a doctor"` — parsed with a pest grammar and
+/// compiled into predicate closures evaluated against a content's matched
+/// terms and lowercased text. The grammar has no feature predicates: the
+/// feature vector in [`policy::PolicyContext`] is not read by any compiled rule.
+pub mod policy {
+    use super::*;
+    use pest::Parser;
+    use std::collections::HashSet;
+
+    #[derive(pest_derive::Parser)]
+    #[grammar = "policy.pest"]
+    struct PolicyParser;
+
+    /// The content-derived inputs a compiled policy is evaluated against.
+    pub struct PolicyContext<'a> {
+        pub content: &'a str,
+        pub features: &'a [f64],
+        pub matched_terms: &'a HashSet<String>,
+    }
+
+    impl PolicyContext<'_> {
+        /// Whether `term` is present: either as an extracted token or as a
+        /// substring of the normalized content (so multi-word phrases match).
+        fn has(&self, term: &str) -> bool {
+            self.matched_terms.contains(term) || self.content.to_lowercase().contains(term)
+        }
+    }
+
+    /// A compiled boolean rule over terms. Evaluated left-to-right without
+    /// operator precedence, matching the flat grammar.
+    pub type Predicate = Box<dyn Fn(&PolicyContext) -> bool + Send + Sync>;
+
+    #[derive(Clone, Copy)]
+    enum Op {
+        And,
+        Or,
+    }
+
+    #[derive(Clone)]
+    struct Atom {
+        text: String,
+        negated: bool,
+    }
+
+    /// Parse and compile `source` into a predicate. Returns a human-readable
+    /// message (with pest's location context) when the expression is malformed.
+    pub fn compile(source: &str) -> Result<Predicate, String> {
+        let mut pairs = PolicyParser::parse(Rule::policy, source)
+            .map_err(|e| format!("policy parse error: {}", e))?;
+        let policy = pairs.next().ok_or_else(|| "empty policy".to_string())?;
+        let expression = policy
+            .into_inner()
+            .find(|p| p.rule() == Rule::expression)
+            .ok_or_else(|| "policy has no expression".to_string())?;
+
+        let mut atoms: Vec<Atom> = Vec::new();
+        let mut ops: Vec<Op> = Vec::new();
+        for pair in expression.into_inner() {
+            match pair.rule() {
+                Rule::term => atoms.push(parse_term(pair)),
+                Rule::operator => ops.push(match pair.as_str().to_ascii_uppercase().as_str() {
+                    "OR" => Op::Or,
+                    _ => Op::And,
+                }),
+                _ => {}
+            }
+        }
+
+        if atoms.is_empty() {
+            return Err("policy has no terms".to_string());
+        }
+
+        // Terms that exclude content when present. Negation wins over a positive
+        // requirement for the same term, so a negated term's presence fails the
+        // whole rule regardless of the boolean structure.
+        let negated: Vec<String> = atoms
+            .iter()
+            .filter(|a| a.negated)
+            .map(|a| a.text.clone())
+            .collect();
+
+        Ok(Box::new(move |ctx: &PolicyContext| {
+            let mut acc = eval_atom(&atoms[0], ctx);
+            for (i, op) in ops.iter().enumerate() {
+                let rhs = eval_atom(&atoms[i + 1], ctx);
+                acc = match op {
+                    Op::And => acc && rhs,
+                    Op::Or => acc || rhs,
+                };
+            }
+            let negation_violated = negated.iter().any(|t| ctx.has(t));
+            acc && !negation_violated
+        }))
+    }
+
+    fn parse_term(pair: pest::iterators::Pair<Rule>) -> Atom {
+        let mut negated = false;
+        let mut text = String::new();
+        for inner in pair.into_inner() {
+            match inner.rule() {
+                Rule::negation => negated = true,
+                Rule::atom => text = atom_text(inner),
+                _ => {}
+            }
+        }
+        Atom {
+            text: text.to_lowercase(),
+            negated,
+        }
+    }
+
+    fn atom_text(pair: pest::iterators::Pair<Rule>) -> String {
+        // For a quoted phrase keep only the inner text; an identifier is its span.
+        match pair.clone().into_inner().find(|p| p.rule() == Rule::phrase) {
+            Some(phrase) => phrase
+                .into_inner()
+                .next()
+                .map(|p| p.as_str().to_string())
+                .unwrap_or_default(),
+            None => pair.as_str().trim_matches('"').to_string(),
+        }
+    }
+
+    fn eval_atom(atom: &Atom, ctx: &PolicyContext) -> bool {
+        let present = ctx.has(&atom.text);
+        if atom.negated {
+            !present
+        } else {
+            present
+        }
+    }
+}
+
 pub struct ConstitutionalAIAnalyzer {
     principles: Vec<ConstitutionalPrinciple>,
     evaluators: HashMap<PrincipleCategory, Box<dyn ConstitutionalPrincipleEvaluator + Send + Sync>>,
+    policies: Vec<(String, policy::Predicate)>,
 }
 
 impl ConstitutionalAIAnalyzer {
@@ -669,9 +1366,20 @@ impl ConstitutionalAIAnalyzer {
         Self {
             principles: Self::default_principles(),
             evaluators: HashMap::new(),
+            policies: Vec::new(),
         }
     }
 
+    /// Register a custom policy rule authored in the DSL (see [`policy`]). The
+    /// rule is compiled once; a parse error is surfaced as
+    /// [`SafetyAnalysisError::InvalidContent`] with pest's location context.
+    pub fn add_policy(&mut self, name: &str, source: &str) -> SafetyResult<()> {
+        let predicate =
+            policy::compile(source).map_err(SafetyAnalysisError::InvalidContent)?;
+        self.policies.push((name.to_string(), predicate));
+        Ok(())
+    }
+
     fn default_principles() -> Vec<ConstitutionalPrinciple> {
         vec![
             ConstitutionalPrinciple {
@@ -701,18 +1409,98 @@ impl ConstitutionalAIAnalyzer {
         ]
     }
 
+    /// Register a custom evaluator for a principle category, letting users ship
+    /// domain-specific constitutions without recompiling.
+    pub fn register_evaluator(
+        &mut self,
+        category: PrincipleCategory,
+        evaluator: Box<dyn ConstitutionalPrincipleEvaluator + Send + Sync>,
+    ) {
+        self.evaluators.insert(category, evaluator);
+    }
+
     pub fn analyze_constitutional_compliance(&self, content: &str) -> SafetyResult<ConstitutionalAnalysis> {
         let mut principle_scores = Vec::new();
         let mut total_weighted_score = 0.0;
         let mut total_weight = 0.0;
+        let mut enforced_human_review = false;
 
         for principle in &self.principles {
-            let score = self.evaluate_single_principle(content, principle)?;
+            let mut score = self.evaluate_single_principle(content, principle)?;
+
+            // Honor the principle's enforcement level.
+            match principle.enforcement_level {
+                EnforcementLevel::Block if !score.violations.is_empty() => {
+                    enforced_human_review = true;
+                }
+                EnforcementLevel::Escalate => {
+                    for violation in &mut score.violations {
+                        violation.severity = escalate(&violation.severity);
+                    }
+                }
+                EnforcementLevel::Rewrite => {
+                    for violation in &mut score.violations {
+                        if violation.suggested_fix.is_none() {
+                            violation.suggested_fix =
+                                Some(format!("Rewrite to satisfy: {}", principle.name));
+                        }
+                    }
+                }
+                _ => {}
+            }
+
             total_weighted_score += score.score * principle.weight;
             total_weight += principle.weight;
             principle_scores.push(score);
         }
 
+        // Evaluate user-configured DSL policies. A matching policy describes a
+        // prohibited pattern, so a hit contributes a zero-scored violation.
+        if !self.policies.is_empty() {
+            let features = text_processing::extract_features(content);
+            let matched_terms: std::collections::HashSet<String> =
+                text_processing::TextAnalyzer::for_language("english")
+                    .tokenize(content)
+                    .into_iter()
+                    .collect();
+            let ctx = policy::PolicyContext {
+                content,
+                features: &features,
+                matched_terms: &matched_terms,
+            };
+
+            let mut violations = Vec::new();
+            for (name, predicate) in &self.policies {
+                if predicate(&ctx) {
+                    violations.push(Violation {
+                        location: TextLocation {
+                            start: 0,
+                            end: content.len(),
+                            line: None,
+                            column: None,
+                        },
+                        severity: Severity::High,
+                        description: format!("policy '{}' matched", name),
+                        suggested_fix: None,
+                    });
+                }
+            }
+
+            let score = if violations.is_empty() { 1.0 } else { 0.0 };
+            if !violations.is_empty() {
+                enforced_human_review = true;
+            }
+            total_weighted_score += score;
+            total_weight += 1.0;
+            principle_scores.push(PrincipleScore {
+                principle_id: "custom_policies".to_string(),
+                score,
+                confidence: 0.9,
+                explanation: "User-configured policy DSL evaluation".to_string(),
+                violations,
+            });
+        }
+
         let overall_compliance = if total_weight > 0.0 {
             total_weighted_score / total_weight
         } else {
@@ -720,8 +1508,11 @@ impl ConstitutionalAIAnalyzer {
         };
 
         let recommendations = self.generate_recommendations(&principle_scores);
-        let requires_human_review = overall_compliance < 0.7 || 
-            principle_scores.iter().any(|s| s.violations.iter().any(|v| v.severity == Severity::Critical));
+        let requires_human_review = enforced_human_review
+            || overall_compliance < 0.7
+            || principle_scores
+                .iter()
+                .any(|s| s.violations.iter().any(|v| v.severity == Severity::Critical));
 
         Ok(ConstitutionalAnalysis {
             overall_compliance,
@@ -732,51 +1523,144 @@ impl ConstitutionalAIAnalyzer {
     }
 
     fn evaluate_single_principle(&self, content: &str, principle: &ConstitutionalPrinciple) -> SafetyResult<PrincipleScore> {
-        // Placeholder implementation - in real system would use ML models
-        let score = self.calculate_principle_score(content, principle);
-        let confidence = 0.85;
-        let explanation = format!("Evaluated content against principle: {}", principle.name);
-        let violations = self.detect_violations(content, principle);
+        // Dispatch to a registered category evaluator when one exists; otherwise
+        // treat the principle as satisfied (no rules to apply).
+        if let Some(evaluator) = self.evaluators.get(&principle.category) {
+            return evaluator.evaluate_principle(content, principle);
+        }
 
         Ok(PrincipleScore {
             principle_id: principle.id.clone(),
-            score,
-            confidence,
-            explanation,
-            violations,
+            score: 1.0,
+            confidence: 0.5,
+            explanation: format!("No evaluator registered for principle: {}", principle.name),
+            violations: Vec::new(),
         })
     }
 
-    fn calculate_principle_score(&self, _content: &str, _principle: &ConstitutionalPrinciple) -> f64 {
-        // Placeholder - would implement actual scoring logic
-        0.85
+    fn generate_recommendations(&self, scores: &[PrincipleScore]) -> Vec<String> {
+        scores
+            .iter()
+            .flat_map(|s| s.violations.iter())
+            .filter_map(|v| v.suggested_fix.clone())
+            .collect()
     }
 
-    fn detect_violations(&self, _content: &str, _principle: &ConstitutionalPrinciple) -> Vec<Violation> {
-        // Placeholder - would implement actual violation detection
-        Vec::new()
-    }
+    /// Load a principle set and its detection rules from an external JSON
+    /// definition, replacing the defaults and (re)building a rule-based
+    /// evaluator per category. Users ship domain-specific constitutions this
+    /// way without recompiling.
+    #[cfg(feature = "serde")]
+    pub fn load_principles(&mut self, path: &str) -> SafetyResult<()> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| SafetyAnalysisError::SerializationError(e.to_string()))?;
+        let set: PrincipleSet = serde_json::from_str(&contents)
+            .map_err(|e| SafetyAnalysisError::SerializationError(e.to_string()))?;
+
+        let mut principles = Vec::new();
+        let mut by_category: HashMap<PrincipleCategory, Vec<(ConstitutionalPrinciple, Vec<DetectionRule>)>> =
+            HashMap::new();
+
+        for def in set.principles {
+            let principle = ConstitutionalPrinciple {
+                id: def.id,
+                name: def.name,
+                description: def.description,
+                weight: def.weight,
+                category: def.category.clone(),
+                enforcement_level: def.enforcement_level,
+            };
 
-    fn generate_recommendations(&self, _scores: &[PrincipleScore]) -> Vec<String> {
-        vec![
-            "Consider reviewing content for potential bias".to_string(),
-            "Ensure factual accuracy of claims".to_string(),
-            "Review tone for helpfulness".to_string(),
-        ]
+            let mut rules = Vec::new();
+            for rule in def.rules {
+                let forbidden_patterns = rule
+                    .forbidden_patterns
+                    .iter()
+                    .map(|p| regex::Regex::new(p))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| SafetyAnalysisError::InvalidContent(e.to_string()))?;
+                rules.push(DetectionRule {
+                    description: rule.description,
+                    severity: rule.severity,
+                    forbidden_keywords: rule.forbidden_keywords,
+                    forbidden_patterns,
+                    required_disclaimers: rule.required_disclaimers,
+                    suggested_fix: rule.suggested_fix,
+                });
+            }
+
+            principles.push(principle.clone());
+            by_category
+                .entry(principle.category.clone())
+                .or_default()
+                .push((principle, rules));
+        }
+
+        self.principles = principles;
+        self.evaluators.clear();
+        for (category, grouped) in by_category {
+            self.register_evaluator(category, Box::new(RuleBasedEvaluator::new(grouped)));
+        }
+
+        Ok(())
     }
 }
 
-// High-performance text processing utilities
-pub mod text_processing {
-    use super::*;
+/// External principle-set definition, deserialized from JSON.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PrincipleSet {
+    pub principles: Vec<PrincipleDefinition>,
+}
 
-    pub fn extract_features(text: &str) -> Vec<f64> {
-        let mut features = Vec::new();
-        
-        // Length features
-        features.push(text.len() as f64);
-        features.push(text.chars().count() as f64);
-        features.push(text.lines().count() as f64);
+/// One principle plus its detection rules, as authored in an external file.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PrincipleDefinition {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub weight: f64,
+    pub category: PrincipleCategory,
+    pub enforcement_level: EnforcementLevel,
+    #[serde(default)]
+    pub rules: Vec<DetectionRuleDef>,
+}
+
+/// A serializable detection rule (regex patterns stay as strings until loaded).
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DetectionRuleDef {
+    #[serde(default)]
+    pub description: String,
+    #[serde(default = "default_rule_severity")]
+    pub severity: Severity,
+    #[serde(default)]
+    pub forbidden_keywords: Vec<String>,
+    #[serde(default)]
+    pub forbidden_patterns: Vec<String>,
+    #[serde(default)]
+    pub required_disclaimers: Vec<String>,
+    #[serde(default)]
+    pub suggested_fix: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+fn default_rule_severity() -> Severity {
+    Severity::Medium
+}
+
+// High-performance text processing utilities
+pub mod text_processing {
+    use super::*;
+
+    pub fn extract_features(text: &str) -> Vec<f64> {
+        let mut features = Vec::new();
+        
+        // Length features
+        features.push(text.len() as f64);
+        features.push(text.chars().count() as f64);
+        features.push(text.lines().count() as f64);
         
         // Character frequency features
         let char_counts = count_character_types(text);
@@ -812,48 +1696,436 @@ pub mod text_processing {
     }
 
     fn extract_word_features(text: &str) -> Vec<f64> {
-        let words: Vec<&str> = text.split_whitespace().collect();
+        let words = TextAnalyzer::for_language("english").tokenize(text);
         let mut features = Vec::new();
-        
+
         features.push(words.len() as f64);
-        
+
         if !words.is_empty() {
-            let avg_word_length: f64 = words.iter().map(|w| w.len()).sum::<usize>() as f64 / words.len() as f64;
+            // Count by Unicode scalar value rather than UTF-8 byte length so
+            // multibyte scripts report comparable word lengths.
+            let lengths: Vec<usize> = words.iter().map(|w| w.chars().count()).collect();
+            let avg_word_length: f64 = lengths.iter().sum::<usize>() as f64 / words.len() as f64;
             features.push(avg_word_length);
-            
-            let max_word_length = words.iter().map(|w| w.len()).max().unwrap_or(0) as f64;
-            features.push(max_word_length);
-            
-            let min_word_length = words.iter().map(|w| w.len()).min().unwrap_or(0) as f64;
-            features.push(min_word_length);
+            features.push(*lengths.iter().max().unwrap_or(&0) as f64);
+            features.push(*lengths.iter().min().unwrap_or(&0) as f64);
         } else {
             features.extend(vec![0.0; 3]);
         }
-        
+
         features
     }
 
+    /// AFINN-derived valence lexicon, embedded as a JSON object mapping each
+    /// word or multi-word phrase to an integer score in the range -5..=5. This
+    /// is a curated subset — including the multi-word phrase entries AFINN
+    /// carries — rather than the full upstream list. Parsed once on first use
+    /// and shared across calls.
+    static AFINN_JSON: &str = include_str!("afinn.json");
+
+    fn afinn_lexicon() -> &'static HashMap<String, i32> {
+        static LEXICON: std::sync::OnceLock<HashMap<String, i32>> = std::sync::OnceLock::new();
+        LEXICON.get_or_init(|| {
+            serde_json::from_str(AFINN_JSON).unwrap_or_default()
+        })
+    }
+
+    /// Longest lexicon entry measured in whitespace-delimited words, used to
+    /// bound the greedy phrase-matching window in [`extract_sentiment_features`].
+    fn afinn_max_phrase_len() -> usize {
+        static MAX: std::sync::OnceLock<usize> = std::sync::OnceLock::new();
+        *MAX.get_or_init(|| {
+            afinn_lexicon()
+                .keys()
+                .map(|k| k.split_whitespace().count())
+                .max()
+                .unwrap_or(1)
+        })
+    }
+
+    /// Tokens that negate the valence of the word that immediately follows them.
+    /// The `Simple` tokenizer splits contractions on the apostrophe, so
+    /// `"isn't"` arrives as `"isn"` followed by `"t"` (likewise `"wasn"`,
+    /// `"don"`, `"can"`); those forms are listed here, and the scorer carries
+    /// negation across the `"t"`, so contracted negators still flip valence.
+    const NEGATORS: [&str; 8] = [
+        "not", "no", "never", "isn", "wasn", "don", "can", "without",
+    ];
+
+    /// Lexicon-based sentiment scoring modeled on AFINN. Tokenizes the
+    /// preprocessed text and sums the valence of each matched entry, preferring
+    /// the longest multi-word phrase that matches at each position so the
+    /// lexicon's phrase entries are reachable, then emits
+    /// `[score, comparative, positive_count, negative_count]`. A matched entry is
+    /// negated when the preceding token is in [`NEGATORS`], or is a contraction's
+    /// `"t"` that directly follows such a negator. Empty input yields a
+    /// zeroed vector rather than dividing by zero.
     fn extract_sentiment_features(text: &str) -> Vec<f64> {
-        // Placeholder for sentiment analysis
-        // In real implementation would use proper sentiment analysis
-        let positive_words = ["good", "great", "excellent", "positive", "happy"];
-        let negative_words = ["bad", "terrible", "awful", "negative", "sad"];
-        
-        let text_lower = text.to_lowercase();
-        let positive_count = positive_words.iter().filter(|&&word| text_lower.contains(word)).count() as f64;
-        let negative_count = negative_words.iter().filter(|&&word| text_lower.contains(word)).count() as f64;
-        
-        vec![positive_count, negative_count]
+        let lexicon = afinn_lexicon();
+        let max_phrase = afinn_max_phrase_len();
+        let cleaned = preprocess_text(text);
+        let tokens: Vec<&str> = cleaned.split_whitespace().collect();
+
+        if tokens.is_empty() {
+            return vec![0.0; 4];
+        }
+
+        let mut score = 0i32;
+        let mut positive_count = 0.0;
+        let mut negative_count = 0.0;
+        let mut negate_next = false;
+
+        let mut i = 0;
+        while i < tokens.len() {
+            // Greedily prefer the longest phrase that matches starting here, so
+            // a multi-word entry wins over its leading single word.
+            let window = max_phrase.min(tokens.len() - i);
+            let mut matched: Option<(usize, i32)> = None;
+            for len in (1..=window).rev() {
+                let phrase = tokens[i..i + len].join(" ");
+                if let Some(&valence) = lexicon.get(&phrase) {
+                    matched = Some((len, valence));
+                    break;
+                }
+            }
+
+            let len = if let Some((len, valence)) = matched {
+                let valence = if negate_next { -valence } else { valence };
+                score += valence;
+                if valence > 0 {
+                    positive_count += 1.0;
+                } else if valence < 0 {
+                    negative_count += 1.0;
+                }
+                len
+            } else {
+                1
+            };
+
+            // A contraction's trailing "t" (from "isn't", "don't") sits between
+            // the negator and the word it negates, so it carries negation over.
+            let last = tokens[i + len - 1];
+            negate_next =
+                NEGATORS.contains(&last) || (negate_next && matched.is_none() && last == "t");
+            i += len;
+        }
+
+        let comparative = score as f64 / tokens.len() as f64;
+        vec![score as f64, comparative, positive_count, negative_count]
     }
 
     pub fn preprocess_text(text: &str) -> String {
-        text.chars()
-            .filter(|c| c.is_alphanumeric() || c.is_whitespace())
-            .collect::<String>()
-            .split_whitespace()
-            .collect::<Vec<&str>>()
-            .join(" ")
-            .to_lowercase()
+        TextAnalyzer::for_language("english").tokenize(text).join(" ")
+    }
+
+    /// The base tokenizer a [`TextAnalyzer`] splits on before filters run.
+    #[derive(Debug, Clone)]
+    pub enum Tokenizer {
+        /// Split on Unicode whitespace. Fast, but collapses scripts that do not
+        /// delimit words with spaces (CJK, Thai) into a single token.
+        Whitespace,
+        /// Split on any non-alphanumeric boundary, keeping runs of letters and
+        /// digits. Handles punctuation-glued input the whitespace splitter misses.
+        Simple,
+        /// Character n-grams of the given width, a script-agnostic fallback that
+        /// yields meaningful overlap counts even without word boundaries.
+        NGram(usize),
+        /// Dictionary (jieba-style) segmentation for scripts without whitespace
+        /// word boundaries, using forward maximum matching.
+        #[cfg(feature = "cjk")]
+        Dictionary,
+    }
+
+    impl Tokenizer {
+        fn split(&self, text: &str) -> Vec<String> {
+            match self {
+                Tokenizer::Whitespace => {
+                    text.split_whitespace().map(|t| t.to_string()).collect()
+                }
+                Tokenizer::Simple => text
+                    .split(|c: char| !c.is_alphanumeric())
+                    .filter(|t| !t.is_empty())
+                    .map(|t| t.to_string())
+                    .collect(),
+                Tokenizer::NGram(width) => {
+                    let chars: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+                    let width = (*width).max(1);
+                    if chars.len() < width {
+                        return if chars.is_empty() {
+                            Vec::new()
+                        } else {
+                            vec![chars.into_iter().collect()]
+                        };
+                    }
+                    chars
+                        .windows(width)
+                        .map(|w| w.iter().collect::<String>())
+                        .collect()
+                }
+                #[cfg(feature = "cjk")]
+                Tokenizer::Dictionary => cjk::segment(text),
+            }
+        }
+    }
+
+    /// Post-tokenization transforms applied in a fixed order. A stage that is
+    /// disabled is skipped, so the same [`TextAnalyzer`] can serve a Latin script
+    /// with full stemming and a CJK script with none.
+    #[derive(Debug, Clone)]
+    pub struct FilterChain {
+        pub lowercase: bool,
+        /// Strip diacritics to their base ASCII letter (e.g. "café" -> "cafe").
+        pub ascii_fold: bool,
+        pub remove_stop_words: bool,
+        /// Apply the Porter stemmer so "running"/"runs" collapse to "run".
+        pub stem: bool,
+        /// Drop tokens longer than this many characters (likely hashes or noise).
+        pub max_token_len: Option<usize>,
+    }
+
+    impl FilterChain {
+        fn apply(&self, token: &str) -> Option<String> {
+            let mut t = token.to_string();
+            if self.lowercase {
+                t = t.to_lowercase();
+            }
+            if self.ascii_fold {
+                t = ascii_fold(&t);
+            }
+            if let Some(max) = self.max_token_len {
+                if t.chars().count() > max {
+                    return None;
+                }
+            }
+            if self.remove_stop_words && STOP_WORDS.contains(&t.as_str()) {
+                return None;
+            }
+            if self.stem {
+                t = porter_stem(&t);
+            }
+            if t.is_empty() {
+                None
+            } else {
+                Some(t)
+            }
+        }
+    }
+
+    /// A configurable tokenization pipeline: one base [`Tokenizer`] followed by a
+    /// [`FilterChain`]. Selecting stages per language lets word counts and lengths
+    /// stay meaningful across scripts that the old `split_whitespace` path broke on.
+    #[derive(Debug, Clone)]
+    pub struct TextAnalyzer {
+        pub tokenizer: Tokenizer,
+        pub filters: FilterChain,
+    }
+
+    impl TextAnalyzer {
+        /// Build the pipeline appropriate for `language`. CJK languages use
+        /// dictionary segmentation when the `cjk` feature is enabled and fall back
+        /// to character n-grams otherwise; Latin scripts use the simple tokenizer
+        /// with lowercasing, ASCII folding and a 64-character token cap. Neither
+        /// pipeline enables stemming or stop-word removal.
+        pub fn for_language(language: &str) -> Self {
+            match language.to_lowercase().as_str() {
+                "chinese" | "japanese" | "korean" | "zh" | "ja" | "ko" => TextAnalyzer {
+                    #[cfg(feature = "cjk")]
+                    tokenizer: Tokenizer::Dictionary,
+                    #[cfg(not(feature = "cjk"))]
+                    tokenizer: Tokenizer::NGram(2),
+                    filters: FilterChain {
+                        lowercase: true,
+                        ascii_fold: false,
+                        remove_stop_words: false,
+                        stem: false,
+                        max_token_len: None,
+                    },
+                },
+                _ => TextAnalyzer {
+                    tokenizer: Tokenizer::Simple,
+                    filters: FilterChain {
+                        lowercase: true,
+                        ascii_fold: true,
+                        remove_stop_words: false,
+                        stem: false,
+                        max_token_len: Some(64),
+                    },
+                },
+            }
+        }
+
+        /// Run `text` through the base tokenizer and every enabled filter,
+        /// returning the surviving tokens in order.
+        pub fn tokenize(&self, text: &str) -> Vec<String> {
+            self.tokenizer
+                .split(text)
+                .iter()
+                .filter_map(|t| self.filters.apply(t))
+                .collect()
+        }
+    }
+
+    /// A compact English stop-word list, applied when a [`FilterChain`] sets
+    /// `remove_stop_words`.
+    const STOP_WORDS: &[&str] = &[
+        "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "has", "he",
+        "in", "is", "it", "its", "of", "on", "that", "the", "to", "was", "were",
+        "will", "with",
+    ];
+
+    /// Strip combining diacritics from common Latin-1/Latin Extended letters,
+    /// leaving the base ASCII letter. Characters with no mapping pass through.
+    fn ascii_fold(token: &str) -> String {
+        token
+            .chars()
+            .map(|c| match c {
+                'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+                'ç' => 'c',
+                'è' | 'é' | 'ê' | 'ë' => 'e',
+                'ì' | 'í' | 'î' | 'ï' => 'i',
+                'ñ' => 'n',
+                'ò' | 'ó' | 'ô' | 'õ' | 'ö' => 'o',
+                'ù' | 'ú' | 'û' | 'ü' => 'u',
+                'ý' | 'ÿ' => 'y',
+                other => other,
+            })
+            .collect()
+    }
+
+    /// A pragmatic Porter stemmer covering the common suffix steps. It is not a
+    /// byte-for-byte port of the reference algorithm but collapses the inflections
+    /// that matter for feature extraction ("running"/"runs" -> "run").
+    fn porter_stem(word: &str) -> String {
+        if word.len() <= 2 || !word.chars().all(|c| c.is_ascii_alphabetic()) {
+            return word.to_string();
+        }
+        let mut w = word.to_string();
+
+        // Step 1a: plurals.
+        if w.ends_with("sses") {
+            w.truncate(w.len() - 2);
+        } else if w.ends_with("ies") {
+            w.truncate(w.len() - 2);
+        } else if w.ends_with('s') && !w.ends_with("ss") {
+            w.truncate(w.len() - 1);
+        }
+
+        // Step 1b: past tense and gerunds, keeping a vowel in the stem.
+        if w.ends_with("eed") {
+            if measure(&w[..w.len() - 3]) > 0 {
+                w.truncate(w.len() - 1);
+            }
+        } else if w.ends_with("ed") && contains_vowel(&w[..w.len() - 2]) {
+            w.truncate(w.len() - 2);
+            w = fix_step1b(w);
+        } else if w.ends_with("ing") && contains_vowel(&w[..w.len() - 3]) {
+            w.truncate(w.len() - 3);
+            w = fix_step1b(w);
+        }
+
+        // Step 1c: terminal y -> i when a vowel precedes.
+        if w.ends_with('y') && contains_vowel(&w[..w.len() - 1]) {
+            w.truncate(w.len() - 1);
+            w.push('i');
+        }
+
+        w
+    }
+
+    /// After stripping "ed"/"ing", restore a dropped final "e" or undo doubled
+    /// consonants so the stem stays pronounceable.
+    fn fix_step1b(mut w: String) -> String {
+        if w.ends_with("at") || w.ends_with("bl") || w.ends_with("iz") {
+            w.push('e');
+        } else {
+            let bytes = w.as_bytes();
+            let n = bytes.len();
+            if n >= 2 && bytes[n - 1] == bytes[n - 2] && !b"lsz".contains(&bytes[n - 1]) {
+                w.truncate(n - 1);
+            }
+        }
+        w
+    }
+
+    /// Count vowel-consonant sequences, the Porter `m` measure of a stem.
+    fn measure(stem: &str) -> usize {
+        let mut m = 0;
+        let mut prev_vowel = false;
+        for (i, c) in stem.chars().enumerate() {
+            let vowel = is_vowel(c, i, stem);
+            if prev_vowel && !vowel {
+                m += 1;
+            }
+            prev_vowel = vowel;
+        }
+        m
+    }
+
+    fn contains_vowel(stem: &str) -> bool {
+        stem.chars().enumerate().any(|(i, c)| is_vowel(c, i, stem))
+    }
+
+    fn is_vowel(c: char, index: usize, stem: &str) -> bool {
+        match c {
+            'a' | 'e' | 'i' | 'o' | 'u' => true,
+            'y' => {
+                // 'y' is a vowel only when the preceding letter is a consonant.
+                index > 0
+                    && !stem
+                        .chars()
+                        .nth(index - 1)
+                        .map(|p| is_vowel(p, index - 1, stem))
+                        .unwrap_or(false)
+            }
+            _ => false,
+        }
+    }
+
+    /// Dictionary segmentation for scripts without whitespace word boundaries.
+    #[cfg(feature = "cjk")]
+    mod cjk {
+        use std::collections::HashSet;
+        use std::sync::OnceLock;
+
+        /// A minimal embedded dictionary; a production build would load the full
+        /// jieba dictionary from disk.
+        static DICTIONARY_WORDS: &str = include_str!("cjk_dict.txt");
+
+        fn dictionary() -> &'static HashSet<String> {
+            static DICT: OnceLock<HashSet<String>> = OnceLock::new();
+            DICT.get_or_init(|| {
+                DICTIONARY_WORDS
+                    .lines()
+                    .map(|l| l.trim())
+                    .filter(|l| !l.is_empty())
+                    .map(|l| l.to_string())
+                    .collect()
+            })
+        }
+
+        /// Forward maximum matching: at each position greedily take the longest
+        /// dictionary word, falling back to a single character when none matches.
+        pub fn segment(text: &str) -> Vec<String> {
+            let dict = dictionary();
+            const MAX_WORD: usize = 6;
+            let chars: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+            let mut tokens = Vec::new();
+            let mut i = 0;
+            while i < chars.len() {
+                let mut matched = 1;
+                let upper = MAX_WORD.min(chars.len() - i);
+                for len in (1..=upper).rev() {
+                    let candidate: String = chars[i..i + len].iter().collect();
+                    if dict.contains(&candidate) {
+                        matched = len;
+                        break;
+                    }
+                }
+                tokens.push(chars[i..i + matched].iter().collect());
+                i += matched;
+            }
+            tokens
+        }
     }
 }
 
@@ -861,6 +2133,7 @@ pub mod text_processing {
 #[no_mangle]
 pub extern "C" fn analyze_safety_ffi(
     content_ptr: *const std::os::raw::c_char,
+    config_ptr: *const std::os::raw::c_char,
     result_ptr: *mut std::os::raw::c_char,
     result_len: usize,
 ) -> i32 {
@@ -875,8 +2148,30 @@ pub extern "C" fn analyze_safety_ffi(
         }
     };
 
-    let analyzer = AdvancedSafetyAnalyzer::new(AnalyzerConfig::default());
-    
+    // An optional TOML config path; a null pointer keeps the defaults.
+    let config = if config_ptr.is_null() {
+        AnalyzerConfig::default()
+    } else {
+        let path = unsafe {
+            match std::ffi::CStr::from_ptr(config_ptr).to_str() {
+                Ok(s) => s,
+                Err(_) => return -2,
+            }
+        };
+        #[cfg(feature = "serde")]
+        match AnalyzerConfig::from_toml_file(path) {
+            Ok(cfg) => cfg,
+            Err(_) => return -6,
+        }
+        #[cfg(not(feature = "serde"))]
+        {
+            let _ = path;
+            AnalyzerConfig::default()
+        }
+    };
+
+    let analyzer = AdvancedSafetyAnalyzer::new(config);
+
     match analyzer.analyze_content(content) {
         Ok(result) => {
             let json_result = match serde_json::to_string(&result) {
@@ -953,6 +2248,619 @@ generate_analyzer!(BiasAnalyzerImpl, String);
 generate_analyzer!(ToxicityAnalyzerImpl, String);
 generate_analyzer!(PrivacyAnalyzerImpl, String);
 
+/// Normalization knobs for [`TermMatchAnalyzer`]. Lowercasing, separator
+/// stripping, and repeated-character collapsing are always applied; leetspeak
+/// folding is optional because it can introduce false positives on legitimate
+/// alphanumeric tokens.
+#[derive(Debug, Clone)]
+pub struct NormalizationConfig {
+    pub fold_leetspeak: bool,
+}
+
+impl Default for NormalizationConfig {
+    fn default() -> Self {
+        Self { fold_leetspeak: true }
+    }
+}
+
+/// The normalized form of an input together with a per-character map back to the
+/// original byte ranges, so a match on the canonical string can be reported as a
+/// span in the caller's original text.
+struct Normalized {
+    text: String,
+    /// For each `char` in `text`, the `[start, end)` byte range in the original
+    /// input it was derived from. Collapsed runs extend the range over the run.
+    spans: Vec<(usize, usize)>,
+}
+
+impl NormalizationConfig {
+    /// Lowercase, drop non-alphanumeric separators, optionally fold leetspeak,
+    /// then collapse runs of the same character so padding cannot defeat a match.
+    fn normalize(&self, input: &str) -> Normalized {
+        let mut text = String::new();
+        let mut spans: Vec<(usize, usize)> = Vec::new();
+        for (offset, ch) in input.char_indices() {
+            let lowered = ch.to_lowercase().next().unwrap_or(ch);
+            // Fold leetspeak before the separator filter so symbolic substitutions
+            // (e.g. `@`→`a`) survive rather than being dropped as punctuation.
+            let folded = if self.fold_leetspeak {
+                match lowered {
+                    '0' => 'o',
+                    '1' => 'i',
+                    '3' => 'e',
+                    '4' | '@' => 'a',
+                    '5' => 's',
+                    '7' => 't',
+                    other => other,
+                }
+            } else {
+                lowered
+            };
+            if !folded.is_alphanumeric() {
+                continue;
+            }
+            let end = offset + ch.len_utf8();
+            if text.chars().last() == Some(folded) {
+                // Collapse the repeat, extending the previous span over it.
+                if let Some(last) = spans.last_mut() {
+                    last.1 = end;
+                }
+                continue;
+            }
+            text.push(folded);
+            spans.push((offset, end));
+        }
+        Normalized { text, spans }
+    }
+}
+
+/// A spoof-resistant blacklist/whitelist term checker. Both the input and the
+/// configured phrases are normalized before comparison, and matching uses
+/// Aho-Corasick for a single-pass multi-pattern scan. A blacklisted match is
+/// suppressed when it overlaps a whitelisted phrase, so allow-list context can
+/// override the block. This is a fast lexical layer distinct from the
+/// model-based scorers.
+pub struct TermMatchAnalyzer {
+    normalization: NormalizationConfig,
+    blacklist: Vec<String>,
+    blacklist_matcher: aho_corasick::AhoCorasick,
+    whitelist_matcher: Option<aho_corasick::AhoCorasick>,
+    severity: Severity,
+}
+
+impl TermMatchAnalyzer {
+    /// Build an analyzer from raw blacklist/whitelist phrases. Phrases are
+    /// normalized with `normalization` so the stored patterns match the same
+    /// canonical form the scanner produces for inputs.
+    pub fn new(
+        blacklist: &[&str],
+        whitelist: &[&str],
+        normalization: NormalizationConfig,
+        severity: Severity,
+    ) -> Self {
+        let norm_terms = |terms: &[&str]| -> Vec<String> {
+            terms
+                .iter()
+                .map(|t| normalization.normalize(t).text)
+                .filter(|t| !t.is_empty())
+                .collect()
+        };
+
+        let blacklist = norm_terms(blacklist);
+        let whitelist = norm_terms(whitelist);
+
+        let blacklist_matcher = aho_corasick::AhoCorasick::new(&blacklist)
+            .expect("valid blacklist patterns");
+        let whitelist_matcher = if whitelist.is_empty() {
+            None
+        } else {
+            Some(
+                aho_corasick::AhoCorasick::new(&whitelist)
+                    .expect("valid whitelist patterns"),
+            )
+        };
+
+        Self {
+            normalization,
+            blacklist,
+            blacklist_matcher,
+            whitelist_matcher,
+            severity,
+        }
+    }
+}
+
+impl SafetyAnalyzer for TermMatchAnalyzer {
+    fn analyze_content(&self, content: &str) -> SafetyResult<SafetyScore> {
+        let start = Instant::now();
+        let normalized = self.normalization.normalize(content);
+
+        // Whitelist spans in normalized coordinates; a blacklist hit overlapping
+        // any of these is treated as allow-listed context and skipped.
+        let whitelist_spans: Vec<(usize, usize)> = match &self.whitelist_matcher {
+            Some(matcher) => matcher
+                .find_iter(&normalized.text)
+                .map(|m| (m.start(), m.end()))
+                .collect(),
+            None => Vec::new(),
+        };
+
+        // Aho-Corasick reports byte offsets, but `spans` is indexed per `char`;
+        // map each char's starting byte back to its char index so multibyte
+        // (non-ASCII) normalized text cannot mis-index or overrun the vector.
+        let byte_to_char: std::collections::HashMap<usize, usize> = normalized
+            .text
+            .char_indices()
+            .enumerate()
+            .map(|(char_idx, (byte_idx, _))| (byte_idx, char_idx))
+            .collect();
+
+        let mut flags = Vec::new();
+        for m in self.blacklist_matcher.find_iter(&normalized.text) {
+            let overlaps_whitelist = whitelist_spans
+                .iter()
+                .any(|&(ws, we)| m.start() < we && ws < m.end());
+            if overlaps_whitelist {
+                continue;
+            }
+
+            // Translate the normalized match back to original byte offsets.
+            let start_char = byte_to_char[&m.start()];
+            let match_chars = normalized.text[m.start()..m.end()].chars().count();
+            let orig_start = normalized.spans[start_char].0;
+            let orig_end = normalized.spans[start_char + match_chars - 1].1;
+            let term = &self.blacklist[m.pattern()];
+
+            flags.push(SafetyFlag {
+                flag_type: FlagType::ContentViolation,
+                severity: self.severity.clone(),
+                message: format!("blacklisted term '{}' matched", term),
+                location: TextLocation {
+                    start: orig_start,
+                    end: orig_end,
+                    line: None,
+                    column: None,
+                },
+                remediation: "redact or remove the flagged term".to_string(),
+                auto_fixable: true,
+            });
+        }
+
+        let flagged = !flags.is_empty();
+        Ok(SafetyScore {
+            overall_score: if flagged { 0.0 } else { 1.0 },
+            confidence: 0.99,
+            categories: HashMap::new(),
+            flags,
+            processing_time_ms: start.elapsed().as_millis() as u64,
+            metadata: AdvancedSafetyAnalyzer::create_metadata(),
+        })
+    }
+
+    fn batch_analyze(&self, contents: &[&str]) -> SafetyResult<Vec<SafetyScore>> {
+        contents.iter().map(|&content| self.analyze_content(content)).collect()
+    }
+
+    fn get_analyzer_info(&self) -> AnalyzerInfo {
+        AnalyzerInfo {
+            name: "TermMatchAnalyzer".to_string(),
+            version: "1.0.0".to_string(),
+            capabilities: vec!["term_blacklist".to_string(), "obfuscation_resistant".to_string()],
+            supported_languages: vec!["english".to_string()],
+            performance_metrics: PerformanceMetrics::default(),
+        }
+    }
+}
+
+// Aggregate reporting over a batch of scores
+//
+// `batch_analyze` returns raw per-item scores; for large runs operators need a
+// distribution, not thousands of rows. This layer summarizes a slice of
+// [`SafetyScore`]s and renders the summary through pluggable formatters.
+pub mod reporting {
+    use super::*;
+
+    /// Summary statistics for one numeric field across a batch.
+    #[derive(Debug, Clone, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct FieldStats {
+        pub mean: f64,
+        pub median: f64,
+        pub stddev: f64,
+        pub min: f64,
+        pub max: f64,
+        pub p50: f64,
+        pub p90: f64,
+        pub p99: f64,
+    }
+
+    impl FieldStats {
+        /// Compute statistics from raw samples. An empty slice yields zeros.
+        pub fn from_samples(samples: &[f64]) -> Self {
+            if samples.is_empty() {
+                return Self::default();
+            }
+            let mut sorted = samples.to_vec();
+            sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+            let n = sorted.len();
+            let mean = sorted.iter().sum::<f64>() / n as f64;
+            let variance =
+                sorted.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n as f64;
+
+            Self {
+                mean,
+                median: percentile(&sorted, 50.0),
+                stddev: variance.sqrt(),
+                min: sorted[0],
+                max: sorted[n - 1],
+                p50: percentile(&sorted, 50.0),
+                p90: percentile(&sorted, 90.0),
+                p99: percentile(&sorted, 99.0),
+            }
+        }
+    }
+
+    /// Nearest-rank percentile over an already-sorted slice.
+    fn percentile(sorted: &[f64], pct: f64) -> f64 {
+        if sorted.is_empty() {
+            return 0.0;
+        }
+        let rank = (pct / 100.0 * sorted.len() as f64).ceil() as usize;
+        let idx = rank.saturating_sub(1).min(sorted.len() - 1);
+        sorted[idx]
+    }
+
+    /// A full batch report: per-field distributions plus flag counts.
+    #[derive(Debug, Clone, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct BatchReport {
+        pub count: usize,
+        pub overall_score: FieldStats,
+        pub confidence: FieldStats,
+        pub processing_time_ms: FieldStats,
+        /// Flag occurrences keyed by flag type name.
+        pub flag_frequency: HashMap<String, u64>,
+    }
+
+    impl BatchReport {
+        /// Summarize a batch of scores.
+        pub fn from_scores(scores: &[SafetyScore]) -> Self {
+            let overall: Vec<f64> = scores.iter().map(|s| s.overall_score).collect();
+            let confidence: Vec<f64> = scores.iter().map(|s| s.confidence).collect();
+            let timings: Vec<f64> =
+                scores.iter().map(|s| s.processing_time_ms as f64).collect();
+
+            let mut flag_frequency: HashMap<String, u64> = HashMap::new();
+            for score in scores {
+                for flag in &score.flags {
+                    *flag_frequency
+                        .entry(format!("{:?}", flag.flag_type))
+                        .or_insert(0) += 1;
+                }
+            }
+
+            Self {
+                count: scores.len(),
+                overall_score: FieldStats::from_samples(&overall),
+                confidence: FieldStats::from_samples(&confidence),
+                processing_time_ms: FieldStats::from_samples(&timings),
+                flag_frequency,
+            }
+        }
+
+        /// Render the report in the requested format.
+        pub fn format(&self, format: ReportFormat) -> String {
+            match format {
+                ReportFormat::Json => self.format_json(),
+                ReportFormat::Terse => self.format_terse(),
+                ReportFormat::Pretty => self.format_pretty(),
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        fn format_json(&self) -> String {
+            serde_json::to_string(self).unwrap_or_else(|_| "{}".to_string())
+        }
+
+        #[cfg(not(feature = "serde"))]
+        fn format_json(&self) -> String {
+            // Minimal hand-rolled JSON when serde is not compiled in.
+            format!(
+                "{{\"count\":{},\"overall_score_mean\":{:.4},\"confidence_mean\":{:.4}}}",
+                self.count, self.overall_score.mean, self.confidence.mean
+            )
+        }
+
+        fn format_terse(&self) -> String {
+            format!(
+                "n={} score(mean={:.3},p90={:.3}) conf(mean={:.3}) time_ms(mean={:.1},p99={:.1}) flags={}",
+                self.count,
+                self.overall_score.mean,
+                self.overall_score.p90,
+                self.confidence.mean,
+                self.processing_time_ms.mean,
+                self.processing_time_ms.p99,
+                self.flag_frequency.values().sum::<u64>(),
+            )
+        }
+
+        fn format_pretty(&self) -> String {
+            let mut out = String::new();
+            out.push_str(&format!("Batch report ({} items)\n", self.count));
+            out.push_str("  field              mean    median   stddev      min      max      p90      p99\n");
+            let row = |name: &str, s: &FieldStats| {
+                format!(
+                    "  {:<16} {:>8.3} {:>8.3} {:>8.3} {:>8.3} {:>8.3} {:>8.3} {:>8.3}\n",
+                    name, s.mean, s.median, s.stddev, s.min, s.max, s.p90, s.p99
+                )
+            };
+            out.push_str(&row("overall_score", &self.overall_score));
+            out.push_str(&row("confidence", &self.confidence));
+            out.push_str(&row("processing_ms", &self.processing_time_ms));
+            if !self.flag_frequency.is_empty() {
+                out.push_str("  flag frequency:\n");
+                let mut entries: Vec<(&String, &u64)> = self.flag_frequency.iter().collect();
+                entries.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+                for (flag, count) in entries {
+                    out.push_str(&format!("    {:<20} {}\n", flag, count));
+                }
+            }
+            out
+        }
+    }
+
+    /// Selects how a [`BatchReport`] is rendered.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ReportFormat {
+        /// Machine-readable JSON.
+        Json,
+        /// One compact line, suitable for logs.
+        Terse,
+        /// Human-readable tabular report.
+        Pretty,
+    }
+}
+
+// Adversarial robustness / evasion-testing harness
+//
+// Turns the analyzer's own pipeline into a red-team loop: mutate a flagged input
+// with evasion operators, re-score the variants, and report how easily the
+// safety score can be flipped. Gated behind the `robustness` feature so the
+// fuzzing surface ships only when requested.
+#[cfg(feature = "robustness")]
+pub mod robustness {
+    use super::*;
+
+    /// A semantics-preserving evasion transform applied to an input.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum MutationOperator {
+        /// Swap Latin letters for confusable Cyrillic homoglyphs.
+        Homoglyph,
+        /// Insert zero-width spaces between characters.
+        ZeroWidth,
+        /// Leetspeak digit substitution (a→4, e→3, …).
+        Leetspeak,
+        /// Inject spaces between characters.
+        Whitespace,
+        /// Wrap the payload in base64.
+        Base64,
+        /// Rotate letters by 13 (ROT13).
+        Rot13,
+    }
+
+    impl MutationOperator {
+        /// Every operator, for exhaustive sweeps.
+        pub fn all() -> Vec<MutationOperator> {
+            vec![
+                MutationOperator::Homoglyph,
+                MutationOperator::ZeroWidth,
+                MutationOperator::Leetspeak,
+                MutationOperator::Whitespace,
+                MutationOperator::Base64,
+                MutationOperator::Rot13,
+            ]
+        }
+
+        /// Stable name for reporting.
+        pub fn name(&self) -> &'static str {
+            match self {
+                MutationOperator::Homoglyph => "homoglyph",
+                MutationOperator::ZeroWidth => "zero_width",
+                MutationOperator::Leetspeak => "leetspeak",
+                MutationOperator::Whitespace => "whitespace",
+                MutationOperator::Base64 => "base64",
+                MutationOperator::Rot13 => "rot13",
+            }
+        }
+
+        /// Apply the transform to `input`.
+        pub fn apply(&self, input: &str) -> String {
+            match self {
+                MutationOperator::Homoglyph => input
+                    .chars()
+                    .map(|c| match c {
+                        'a' => 'а',
+                        'e' => 'е',
+                        'o' => 'о',
+                        'p' => 'р',
+                        'c' => 'с',
+                        'x' => 'х',
+                        other => other,
+                    })
+                    .collect(),
+                MutationOperator::ZeroWidth => {
+                    let mut out = String::new();
+                    for (i, c) in input.chars().enumerate() {
+                        if i > 0 {
+                            out.push('\u{200b}');
+                        }
+                        out.push(c);
+                    }
+                    out
+                }
+                MutationOperator::Leetspeak => input
+                    .chars()
+                    .map(|c| match c.to_ascii_lowercase() {
+                        'a' => '4',
+                        'e' => '3',
+                        'i' => '1',
+                        'o' => '0',
+                        's' => '5',
+                        't' => '7',
+                        _ => c,
+                    })
+                    .collect(),
+                MutationOperator::Whitespace => {
+                    let chars: Vec<String> = input.chars().map(|c| c.to_string()).collect();
+                    chars.join(" ")
+                }
+                MutationOperator::Base64 => base64_encode(input.as_bytes()),
+                MutationOperator::Rot13 => input
+                    .chars()
+                    .map(|c| match c {
+                        'a'..='z' => (((c as u8 - b'a' + 13) % 26) + b'a') as char,
+                        'A'..='Z' => (((c as u8 - b'A' + 13) % 26) + b'A') as char,
+                        _ => c,
+                    })
+                    .collect(),
+            }
+        }
+    }
+
+    /// The outcome of scoring one mutated variant.
+    #[derive(Debug, Clone)]
+    pub struct MutationOutcome {
+        pub operator: String,
+        pub mutated: String,
+        pub score: f64,
+        /// Character-level edit distance from the seed.
+        pub edit_distance: usize,
+    }
+
+    /// Summary of how brittle a seed input's safety score is under evasion.
+    #[derive(Debug, Clone)]
+    pub struct RobustnessReport {
+        pub seed: String,
+        pub target_category: String,
+        pub baseline_score: f64,
+        pub variant_count: usize,
+        /// Variance of `overall_score` across all variants.
+        pub score_variance: f64,
+        /// The smallest-edit mutation that pushed the score below the threshold,
+        /// if any — the cheapest successful evasion.
+        pub weakest: Option<MutationOutcome>,
+    }
+
+    /// Mutate `seed` with each operator, re-score the variants through
+    /// `batch_analyze`, and report the cheapest evasion plus a stability metric.
+    pub fn evaluate<A: SafetyAnalyzer>(
+        analyzer: &A,
+        seed: &str,
+        target_category: &str,
+        operators: &[MutationOperator],
+        quality_threshold: f64,
+    ) -> SafetyResult<RobustnessReport> {
+        let baseline_score = analyzer.analyze_content(seed)?.overall_score;
+
+        let variants: Vec<(MutationOperator, String)> = operators
+            .iter()
+            .map(|op| (*op, op.apply(seed)))
+            .collect();
+        let variant_refs: Vec<&str> = variants.iter().map(|(_, v)| v.as_str()).collect();
+        let scores = analyzer.batch_analyze(&variant_refs)?;
+
+        let outcomes: Vec<MutationOutcome> = variants
+            .iter()
+            .zip(&scores)
+            .map(|((op, mutated), score)| MutationOutcome {
+                operator: op.name().to_string(),
+                mutated: mutated.clone(),
+                score: score.overall_score,
+                edit_distance: edit_distance(seed, mutated),
+            })
+            .collect();
+
+        let score_variance = variance(outcomes.iter().map(|o| o.score));
+        let weakest = outcomes
+            .into_iter()
+            .filter(|o| o.score < quality_threshold)
+            .min_by_key(|o| o.edit_distance);
+
+        Ok(RobustnessReport {
+            seed: seed.to_string(),
+            target_category: target_category.to_string(),
+            baseline_score,
+            variant_count: variants.len(),
+            score_variance,
+            weakest,
+        })
+    }
+
+    /// Fuzz entry point: feed arbitrary bytes through `analyze_content` to hunt
+    /// for panics, timeouts, and resource exhaustion. Wire this into a
+    /// `cargo-fuzz`/`honggfuzz` target — errors are expected and swallowed; only
+    /// a panic fails the run.
+    pub fn fuzz_analyze<A: SafetyAnalyzer>(analyzer: &A, data: &[u8]) {
+        if let Ok(text) = std::str::from_utf8(data) {
+            let _ = analyzer.analyze_content(text);
+        }
+    }
+
+    /// Population variance of a stream of values.
+    fn variance<I: Iterator<Item = f64>>(values: I) -> f64 {
+        let values: Vec<f64> = values.collect();
+        if values.is_empty() {
+            return 0.0;
+        }
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64
+    }
+
+    /// Character-level Levenshtein distance.
+    fn edit_distance(a: &str, b: &str) -> usize {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        let mut prev: Vec<usize> = (0..=b.len()).collect();
+        let mut curr = vec![0usize; b.len() + 1];
+        for (i, &ca) in a.iter().enumerate() {
+            curr[0] = i + 1;
+            for (j, &cb) in b.iter().enumerate() {
+                let cost = if ca == cb { 0 } else { 1 };
+                curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+            }
+            std::mem::swap(&mut prev, &mut curr);
+        }
+        prev[b.len()]
+    }
+
+    /// Minimal standard base64 encoder (used by the `Base64` operator).
+    fn base64_encode(input: &[u8]) -> String {
+        const TABLE: &[u8; 64] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        let mut out = String::new();
+        for chunk in input.chunks(3) {
+            let b0 = chunk[0] as u32;
+            let b1 = *chunk.get(1).unwrap_or(&0) as u32;
+            let b2 = *chunk.get(2).unwrap_or(&0) as u32;
+            let n = (b0 << 16) | (b1 << 8) | b2;
+            out.push(TABLE[((n >> 18) & 0x3f) as usize] as char);
+            out.push(TABLE[((n >> 12) & 0x3f) as usize] as char);
+            out.push(if chunk.len() > 1 {
+                TABLE[((n >> 6) & 0x3f) as usize] as char
+            } else {
+                '='
+            });
+            out.push(if chunk.len() > 2 {
+                TABLE[(n & 0x3f) as usize] as char
+            } else {
+                '='
+            });
+        }
+        out
+    }
+}
+
 // Module re-exports for public API
 pub use text_processing::*;
 
@@ -974,6 +2882,244 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_rule_based_evaluator_flags_and_blocks() {
+        let principle = ConstitutionalPrinciple {
+            id: "harmlessness_1".to_string(),
+            name: "Avoid Harmful Content".to_string(),
+            description: "no harm".to_string(),
+            weight: 1.0,
+            category: PrincipleCategory::Harmlessness,
+            enforcement_level: EnforcementLevel::Block,
+        };
+        let rule = DetectionRule {
+            description: "no violence".to_string(),
+            severity: Severity::High,
+            forbidden_keywords: vec!["kill".to_string()],
+            forbidden_patterns: Vec::new(),
+            required_disclaimers: Vec::new(),
+            suggested_fix: Some("remove threatening language".to_string()),
+        };
+
+        let mut analyzer = ConstitutionalAIAnalyzer::new();
+        analyzer.register_evaluator(
+            PrincipleCategory::Harmlessness,
+            Box::new(RuleBasedEvaluator::new(vec![(principle, vec![rule])])),
+        );
+
+        let result = analyzer
+            .analyze_constitutional_compliance("I will kill the process")
+            .unwrap();
+        assert!(result.requires_human_review);
+        assert!(result
+            .principle_scores
+            .iter()
+            .any(|s| !s.violations.is_empty()));
+    }
+
+    #[test]
+    fn test_term_match_analyzer_resists_obfuscation_and_whitelist() {
+        let analyzer = TermMatchAnalyzer::new(
+            &["badword"],
+            &["badword police"],
+            NormalizationConfig::default(),
+            Severity::High,
+        );
+
+        // Padding and leetspeak should not evade the match.
+        let flagged = analyzer.analyze_content("you are a b@@d-w0rd!").unwrap();
+        assert_eq!(flagged.flags.len(), 1);
+        assert_eq!(flagged.overall_score, 0.0);
+
+        // A whitelisted phrase overrides the blacklisted substring.
+        let allowed = analyzer.analyze_content("call the badword police").unwrap();
+        assert!(allowed.flags.is_empty());
+    }
+
+    #[test]
+    fn test_policy_dsl_negation_wins() {
+        let mut analyzer = ConstitutionalAIAnalyzer::new();
+        analyzer
+            .add_policy("needs_disclaimer", "medical AND NOT disclaimer")
+            .unwrap();
+
+        // Medical content without a disclaimer trips the policy.
+        let flagged = analyzer
+            .analyze_constitutional_compliance("here is some medical guidance")
+            .unwrap();
+        assert!(flagged
+            .principle_scores
+            .iter()
+            .any(|s| s.principle_id == "custom_policies" && !s.violations.is_empty()));
+
+        // The negated term's presence overrides the positive match.
+        let allowed = analyzer
+            .analyze_constitutional_compliance("medical guidance with a disclaimer")
+            .unwrap();
+        assert!(allowed
+            .principle_scores
+            .iter()
+            .filter(|s| s.principle_id == "custom_policies")
+            .all(|s| s.violations.is_empty()));
+    }
+
+    #[test]
+    fn test_batch_report_statistics() {
+        let scores = vec![dummy_score(0.2), dummy_score(0.4), dummy_score(0.6), dummy_score(0.8)];
+        let report = reporting::BatchReport::from_scores(&scores);
+        assert_eq!(report.count, 4);
+        assert!((report.overall_score.mean - 0.5).abs() < 1e-9);
+        assert_eq!(report.overall_score.min, 0.2);
+        assert_eq!(report.overall_score.max, 0.8);
+
+        // Every formatter produces output without panicking.
+        assert!(report.format(reporting::ReportFormat::Terse).contains("n=4"));
+        assert!(report.format(reporting::ReportFormat::Pretty).contains("overall_score"));
+        let _ = report.format(reporting::ReportFormat::Json);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_config_from_toml_str() {
+        let toml = r#"
+            [analyzer]
+            cache_size = 42
+            cache_ttl_ms = 5000
+
+            [thresholds]
+            toxicity = 0.7
+
+            [lexicons]
+            blacklist = "/etc/opensafe/blacklist.txt"
+
+            [analyzers]
+            active = ["bias", "toxicity"]
+        "#;
+        let config = AnalyzerConfig::from_toml_str(toml).unwrap();
+        assert_eq!(config.cache_size, 42);
+        assert_eq!(config.cache_ttl, Some(Duration::from_millis(5000)));
+        assert_eq!(config.category_thresholds.get("toxicity"), Some(&0.7));
+        assert_eq!(config.blacklist_path.as_deref(), Some("/etc/opensafe/blacklist.txt"));
+        assert_eq!(config.active_analyzers, vec!["bias", "toxicity"]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_config_from_toml_reports_location() {
+        // Malformed TOML should surface an error, not silently fall back.
+        let err = AnalyzerConfig::from_toml_str("[analyzer]\ncache_size = ").unwrap_err();
+        assert!(matches!(err, SafetyAnalysisError::SerializationError(_)));
+        if let SafetyAnalysisError::SerializationError(msg) = err {
+            assert!(msg.contains("line"), "error lacks location context: {}", msg);
+        }
+    }
+
+    fn dummy_score(overall: f64) -> SafetyScore {
+        SafetyScore {
+            overall_score: overall,
+            confidence: 1.0,
+            categories: HashMap::new(),
+            flags: Vec::new(),
+            processing_time_ms: 0,
+            metadata: AdvancedSafetyAnalyzer::create_metadata(),
+        }
+    }
+
+    struct StubModel;
+
+    impl AnalysisModel for StubModel {
+        fn analyze(&self, content: &str) -> SafetyResult<SafetyScore> {
+            if content.is_empty() {
+                return Err(SafetyAnalysisError::InvalidContent("empty".to_string()));
+            }
+            let mut categories = HashMap::new();
+            categories.insert(
+                "content".to_string(),
+                CategoryScore {
+                    score: 0.5,
+                    confidence: 1.0,
+                    subcategory_scores: HashMap::new(),
+                    evidence: Vec::new(),
+                    mitigation_suggestions: Vec::new(),
+                },
+            );
+            Ok(SafetyScore {
+                overall_score: 0.5,
+                confidence: 1.0,
+                categories,
+                flags: Vec::new(),
+                processing_time_ms: 1,
+                metadata: AdvancedSafetyAnalyzer::create_metadata(),
+            })
+        }
+
+        fn get_model_info(&self) -> ModelInfo {
+            ModelInfo {
+                name: "stub".to_string(),
+                version: "1.0.0".to_string(),
+                model_type: "stub".to_string(),
+                capabilities: Vec::new(),
+            }
+        }
+
+        fn is_ready(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_parallel_batch_returns_partial_per_index_errors() {
+        let analyzer = AdvancedSafetyAnalyzer::new(AnalyzerConfig::default());
+        analyzer
+            .register_model("stub".to_string(), Box::new(StubModel))
+            .unwrap();
+
+        let results = analyzer
+            .parallel_batch_analyze_indexed(&["ok", "", "fine"])
+            .unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert!(results[2].is_ok());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_safety_score_json_round_trip() {
+        let score = dummy_score(0.73);
+        let json = score.to_json().unwrap();
+        let restored = SafetyScore::from_json(&json).unwrap();
+        assert_eq!(restored.overall_score, 0.73);
+    }
+
+    #[test]
+    fn test_cache_verifies_content_on_collision() {
+        let mut cache = BoundedCache::new(4, None);
+        cache.insert(42, "hello".to_string(), dummy_score(0.1), Instant::now());
+
+        // Same hash, different content must not return the stored score.
+        assert!(cache.get(42, "world").is_none());
+        assert!(cache.get(42, "hello").is_some());
+        assert_eq!(cache.stats.hits, 1);
+        assert_eq!(cache.stats.misses, 1);
+    }
+
+    #[test]
+    fn test_cache_evicts_least_recently_used() {
+        let mut cache = BoundedCache::new(2, None);
+        cache.insert(1, "a".to_string(), dummy_score(0.1), Instant::now());
+        cache.insert(2, "b".to_string(), dummy_score(0.2), Instant::now());
+
+        // Touch key 1 so key 2 becomes the eviction victim.
+        assert!(cache.get(1, "a").is_some());
+        cache.insert(3, "c".to_string(), dummy_score(0.3), Instant::now());
+
+        assert!(cache.get(2, "b").is_none());
+        assert!(cache.get(1, "a").is_some());
+        assert_eq!(cache.stats.evictions, 1);
+    }
+
     #[test]
     fn test_text_preprocessing() {
         let text = "Hello, World! 123";
@@ -6,8 +6,10 @@ use std::sync::Mutex;
 
 mod safety;
 mod interpretability;
+mod aggregation;
+mod toxicity;
 
-use safety::SafetyAnalyzer;
+use safety::{RulePack, SafetyAnalyzer};
 use interpretability::InterpretabilityAnalyzer;
 
 static SAFETY_ANALYZER: Lazy<Mutex<SafetyAnalyzer>> = Lazy::new(|| {
@@ -41,7 +43,10 @@ pub extern "C" fn analyze_safety(text: *const c_char, context: *const c_char) ->
     };
     
     let result = analyzer.analyze(&text_str, context_str.as_deref());
-    
+
+    // Fold the result into the corpus-level profile for drift detection.
+    aggregation::record(&result);
+
     match serde_json::to_string(&result) {
         Ok(json) => match CString::new(json) {
             Ok(cstring) => cstring.into_raw(),
@@ -51,6 +56,25 @@ pub extern "C" fn analyze_safety(text: *const c_char, context: *const c_char) ->
     }
 }
 
+/// Return the current corpus-level aggregate profile as a JSON string. The
+/// caller owns the returned buffer and must release it with `free_string`.
+#[no_mangle]
+pub extern "C" fn safety_profile_snapshot() -> *mut c_char {
+    match aggregation::snapshot_json() {
+        Some(json) => match CString::new(json) {
+            Ok(cstring) => cstring.into_raw(),
+            Err(_) => std::ptr::null_mut(),
+        },
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Reset the accumulated corpus-level profile.
+#[no_mangle]
+pub extern "C" fn reset_safety_profile() {
+    aggregation::reset();
+}
+
 #[no_mangle]
 pub extern "C" fn analyze_interpretability(text: *const c_char) -> *mut c_char {
     let text_str = unsafe {
@@ -76,6 +100,70 @@ pub extern "C" fn analyze_interpretability(text: *const c_char) -> *mut c_char {
     }
 }
 
+/// Redact PII from `text`, returning a JSON object with the sanitized string
+/// and a per-type removal count (e.g. `{"redacted":"...","counts":{"EMAIL":1}}`).
+/// The caller owns the returned buffer and must release it with `free_string`.
+#[no_mangle]
+pub extern "C" fn redact_pii(text: *const c_char) -> *mut c_char {
+    let text_str = unsafe {
+        if text.is_null() {
+            return std::ptr::null_mut();
+        }
+        CStr::from_ptr(text).to_string_lossy()
+    };
+
+    let analyzer = match SAFETY_ANALYZER.lock() {
+        Ok(analyzer) => analyzer,
+        Err(_) => return std::ptr::null_mut(),
+    };
+
+    let report = analyzer.redact(&text_str);
+
+    match serde_json::to_string(&report) {
+        Ok(json) => match CString::new(json) {
+            Ok(cstring) => cstring.into_raw(),
+            Err(_) => std::ptr::null_mut(),
+        },
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Swap the global safety analyzer's rule set at runtime from an external rule
+/// file. Returns 0 on success, or a negative status code: -1 null path, -2
+/// invalid UTF-8 path, -3 failed to load/parse the rule pack, -4 the analyzer
+/// lock was poisoned.
+#[no_mangle]
+pub extern "C" fn reload_rules(path: *const c_char) -> i32 {
+    if path.is_null() {
+        return -1;
+    }
+
+    let path_str = unsafe {
+        match CStr::from_ptr(path).to_str() {
+            Ok(s) => s,
+            Err(_) => return -2,
+        }
+    };
+
+    let pack = match RulePack::from_file(path_str) {
+        Ok(pack) => pack,
+        Err(_) => return -3,
+    };
+
+    let analyzer = match SafetyAnalyzer::from_pack(&pack) {
+        Ok(analyzer) => analyzer,
+        Err(_) => return -3,
+    };
+
+    match SAFETY_ANALYZER.lock() {
+        Ok(mut guard) => {
+            *guard = analyzer;
+            0
+        }
+        Err(_) => -4,
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn free_string(s: *mut c_char) {
     if s.is_null() {
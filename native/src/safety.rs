@@ -1,7 +1,218 @@
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
+use std::ops::Range;
 use regex::Regex;
 use rayon::prelude::*;
+use unicode_normalization::UnicodeNormalization;
+
+/// Which de-obfuscation steps the normalization pipeline runs before matching.
+/// Callers that need literal matching can disable the whole pipeline (see
+/// `SafetyAnalyzer::analyze_with_options`).
+#[derive(Debug, Clone, Copy)]
+pub struct NormalizationOptions {
+    pub nfkc: bool,
+    pub strip_zero_width: bool,
+    pub strip_combining: bool,
+    pub fold_confusables: bool,
+    pub leetspeak: bool,
+}
+
+impl Default for NormalizationOptions {
+    fn default() -> Self {
+        Self {
+            nfkc: true,
+            strip_zero_width: true,
+            strip_combining: true,
+            fold_confusables: true,
+            leetspeak: true,
+        }
+    }
+}
+
+fn is_zero_width(c: char) -> bool {
+    matches!(c, '\u{200B}'..='\u{200D}' | '\u{FEFF}' | '\u{2060}' | '\u{00AD}')
+}
+
+// Combining diacritical marks left over after NFKC (e.g. a base letter plus a
+// free-standing accent). Stripping them folds "ki\u{0301}ll" back to "kill".
+fn is_combining(c: char) -> bool {
+    matches!(c, '\u{0300}'..='\u{036F}' | '\u{1AB0}'..='\u{1AFF}' | '\u{1DC0}'..='\u{1DFF}' | '\u{20D0}'..='\u{20FF}')
+}
+
+// Fold a handful of common Cyrillic/Greek homoglyphs to their ASCII look-alike.
+// Fullwidth forms are already handled by NFKC.
+fn fold_confusable(c: char) -> char {
+    match c {
+        'а' => 'a', 'е' => 'e', 'о' => 'o', 'р' => 'p', 'с' => 'c', 'х' => 'x',
+        'у' => 'y', 'к' => 'k', 'м' => 'm', 'т' => 't', 'н' => 'h', 'в' => 'b',
+        'ѕ' => 's', 'і' => 'i', 'ј' => 'j',
+        'α' => 'a', 'ο' => 'o', 'ρ' => 'p', 'ι' => 'i', 'ν' => 'v',
+        other => other,
+    }
+}
+
+fn deleet(c: char) -> char {
+    match c {
+        '0' => 'o',
+        '1' => 'i',
+        '3' => 'e',
+        '@' => 'a',
+        '$' => 's',
+        other => other,
+    }
+}
+
+/// The result of running the normalization pipeline: the cleaned, lower-cased
+/// text that matching runs against, plus a byte-offset map back into the
+/// original so `evidence` can quote the real source span rather than the
+/// normalized form.
+pub struct NormalizedText {
+    pub text: String,
+    /// `offsets[i]` is the original byte offset of the source character that
+    /// produced normalized byte `i`; the final element is the original length.
+    offsets: Vec<usize>,
+}
+
+impl NormalizedText {
+    pub fn build(original: &str, opts: &NormalizationOptions) -> Self {
+        let mut text = String::with_capacity(original.len());
+        let mut offsets = Vec::with_capacity(original.len() + 1);
+
+        for (orig_offset, c) in original.char_indices() {
+            // NFKC is applied per source char so the offset map stays exact;
+            // this covers fullwidth and other compatibility forms.
+            let expanded: Vec<char> = if opts.nfkc {
+                c.to_string().nfkc().collect()
+            } else {
+                vec![c]
+            };
+
+            for ec in expanded {
+                if opts.strip_zero_width && is_zero_width(ec) {
+                    continue;
+                }
+                if opts.strip_combining && is_combining(ec) {
+                    continue;
+                }
+                let folded = if opts.fold_confusables { fold_confusable(ec) } else { ec };
+                for lc in folded.to_lowercase() {
+                    let sub = if opts.leetspeak { deleet(lc) } else { lc };
+                    let mut buf = [0u8; 4];
+                    let encoded = sub.encode_utf8(&mut buf);
+                    for _ in 0..encoded.len() {
+                        offsets.push(orig_offset);
+                    }
+                    text.push_str(encoded);
+                }
+            }
+        }
+        offsets.push(original.len());
+
+        Self { text, offsets }
+    }
+
+    /// Map a match span in the normalized text back to the corresponding slice
+    /// of the original text.
+    pub fn evidence(&self, original: &str, span: Range<usize>) -> String {
+        let start = self.offsets.get(span.start).copied().unwrap_or(original.len());
+        let end = self.offsets.get(span.end).copied().unwrap_or(original.len());
+        original
+            .get(start..end)
+            .unwrap_or(&self.text[span])
+            .to_string()
+    }
+}
+
+/// A composable, serde-deserializable predicate tree evaluated against the
+/// facts gathered while scanning a piece of text. Modeled on the tagged-enum
+/// filter DSLs used elsewhere in the ecosystem so that rule authors can write
+/// things like "a harmful pattern fired AND no medical context was present".
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "predicate", content = "argument", rename_all = "snake_case")]
+pub enum Predicate {
+    /// True when the named pattern group matched at least once.
+    PatternMatches(String),
+    /// True when the named category accumulated at least one match.
+    CategoryMatches(String),
+    /// True when any matched group's default severity is >= the given level.
+    SeverityAtLeast(String),
+    /// True when the named pattern group matched at least `n` times.
+    MinMatchCount { pattern: String, n: usize },
+    /// True when the analysis context contains the given substring.
+    ContextContains(String),
+    Not(Box<Predicate>),
+    AnyOf(Vec<Predicate>),
+    AllOf(Vec<Predicate>),
+}
+
+/// A user-programmable rule: when `predicate` holds over the accumulated match
+/// facts, `analyze` emits a `Violation` carrying this policy's metadata.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Policy {
+    pub name: String,
+    pub predicate: Predicate,
+    pub severity: String,
+    pub description: String,
+}
+
+/// The facts accumulated while the `check_*` passes scan the text. Policies are
+/// evaluated against this view rather than against the raw regex lists.
+#[derive(Debug, Default, Clone)]
+pub struct MatchFacts {
+    /// Number of matches per pattern group (e.g. "harmful", "bias").
+    counts: HashMap<String, usize>,
+    /// Representative matched spans per pattern group, used for `evidence`.
+    evidence: HashMap<String, Vec<String>>,
+    /// Lower-cased analysis context, if any was supplied.
+    context: Option<String>,
+}
+
+impl MatchFacts {
+    fn record(&mut self, group: &str, evidence: String) {
+        *self.counts.entry(group.to_string()).or_insert(0) += 1;
+        self.evidence
+            .entry(group.to_string())
+            .or_default()
+            .push(evidence);
+    }
+
+    fn count(&self, group: &str) -> usize {
+        self.counts.get(group).copied().unwrap_or(0)
+    }
+
+    fn evidence(&self, group: &str) -> Vec<String> {
+        self.evidence.get(group).cloned().unwrap_or_default()
+    }
+}
+
+impl Predicate {
+    /// Evaluate the predicate against the gathered facts. `severity_rank`
+    /// resolves the default severity of a matched group so `SeverityAtLeast`
+    /// can compare ordinally.
+    fn eval(&self, facts: &MatchFacts, severity_rank: &dyn Fn(&str) -> u8) -> bool {
+        match self {
+            Predicate::PatternMatches(group) | Predicate::CategoryMatches(group) => {
+                facts.count(group) > 0
+            }
+            Predicate::MinMatchCount { pattern, n } => facts.count(pattern) >= *n,
+            Predicate::SeverityAtLeast(level) => {
+                let threshold = severity_rank(level);
+                facts
+                    .counts
+                    .keys()
+                    .any(|group| severity_rank(group) >= threshold)
+            }
+            Predicate::ContextContains(needle) => facts
+                .context
+                .as_deref()
+                .map(|ctx| ctx.contains(&needle.to_lowercase()))
+                .unwrap_or(false),
+            Predicate::Not(inner) => !inner.eval(facts, severity_rank),
+            Predicate::AnyOf(preds) => preds.iter().any(|p| p.eval(facts, severity_rank)),
+            Predicate::AllOf(preds) => preds.iter().all(|p| p.eval(facts, severity_rank)),
+        }
+    }
+}
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SafetyResult {
@@ -20,6 +231,15 @@ pub struct Violation {
     pub evidence: Vec<String>,
 }
 
+/// The result of PII redaction: a safe-to-log copy of the input with each
+/// privacy match replaced by a typed placeholder, plus a per-type count so
+/// downstream systems can audit what was removed.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RedactionReport {
+    pub redacted: String,
+    pub counts: HashMap<String, usize>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AnalysisMetadata {
     pub analysis_time_ms: u64,
@@ -29,13 +249,179 @@ pub struct AnalysisMetadata {
     pub pattern_matches: usize,
 }
 
+/// A single detection rule as authored in an external rule file. A rule owns a
+/// set of regex patterns and the category they contribute to, letting operators
+/// ship detection updates without recompiling the binary.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Rule {
+    pub id: String,
+    pub category: String,
+    pub severity: String,
+    pub confidence: f64,
+    pub patterns: Vec<String>,
+    /// Minimum number of pattern hits required before the rule fires. Hits are
+    /// counted over the rule's whole category, so other rules in the same
+    /// category add to the count.
+    #[serde(default)]
+    pub threshold: Option<usize>,
+    /// Force the lookaround-capable backend even when the fast engine would
+    /// accept the pattern. Fancy syntax is otherwise detected automatically.
+    #[serde(default)]
+    pub fancy: bool,
+}
+
+/// A loadable collection of detection rules. Deserialized from a TOML or YAML
+/// document (chosen by file extension) so rule sets live outside the binary.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RulePack {
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+}
+
+impl RulePack {
+    /// Load a rule pack from a TOML or YAML file, dispatching on extension.
+    pub fn from_file(path: &str) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read rule file {}: {}", path, e))?;
+        if path.ends_with(".yaml") || path.ends_with(".yml") {
+            serde_yaml::from_str(&contents).map_err(|e| format!("invalid YAML rule pack: {}", e))
+        } else {
+            toml::from_str(&contents).map_err(|e| format!("invalid TOML rule pack: {}", e))
+        }
+    }
+}
+
+/// A compiled pattern backed by whichever engine can express it. Simple
+/// patterns use the linear-time `regex` crate; patterns needing lookaround or
+/// backreferences fall back to `fancy-regex`, so both kinds can be iterated over
+/// uniformly by the `check_*` passes.
+pub enum Pattern {
+    Fast(Regex),
+    Fancy(fancy_regex::Regex),
+}
+
+/// Heuristic for syntax the fast `regex` engine rejects: lookaround and
+/// backreferences.
+fn needs_fancy(pattern: &str) -> bool {
+    pattern.contains("(?=")
+        || pattern.contains("(?!")
+        || pattern.contains("(?<=")
+        || pattern.contains("(?<!")
+        || (1..=9).any(|n| pattern.contains(&format!("\\{}", n)))
+}
+
+impl Pattern {
+    /// Compile a pattern, choosing the fancy backend when `force_fancy` is set,
+    /// when the syntax requires it, or when the fast engine rejects it outright.
+    pub fn compile(pattern: &str, force_fancy: bool) -> Result<Self, String> {
+        if force_fancy || needs_fancy(pattern) {
+            return fancy_regex::Regex::new(pattern)
+                .map(Pattern::Fancy)
+                .map_err(|e| e.to_string());
+        }
+        match Regex::new(pattern) {
+            Ok(re) => Ok(Pattern::Fast(re)),
+            Err(_) => fancy_regex::Regex::new(pattern)
+                .map(Pattern::Fancy)
+                .map_err(|e| e.to_string()),
+        }
+    }
+
+    /// The first match's byte range, if any.
+    fn find(&self, text: &str) -> Option<Range<usize>> {
+        match self {
+            Pattern::Fast(re) => re.find(text).map(|m| m.range()),
+            Pattern::Fancy(re) => re.find(text).ok().flatten().map(|m| m.start()..m.end()),
+        }
+    }
+
+    /// Number of non-overlapping matches, used by the privacy check.
+    fn count_matches(&self, text: &str) -> usize {
+        match self {
+            Pattern::Fast(re) => re.find_iter(text).count(),
+            Pattern::Fancy(re) => re.find_iter(text).filter(|m| m.is_ok()).count(),
+        }
+    }
+
+    /// Byte ranges of every non-overlapping match, used by redaction.
+    fn match_ranges(&self, text: &str) -> Vec<Range<usize>> {
+        match self {
+            Pattern::Fast(re) => re.find_iter(text).map(|m| m.range()).collect(),
+            Pattern::Fancy(re) => re
+                .find_iter(text)
+                .filter_map(|m| m.ok())
+                .map(|m| m.start()..m.end())
+                .collect(),
+        }
+    }
+}
+
+impl From<Regex> for Pattern {
+    fn from(re: Regex) -> Self {
+        Pattern::Fast(re)
+    }
+}
+
+/// Static metadata about a pattern group: the `Violation` fields emitted for it
+/// and the severity used both for the violation and for `SeverityAtLeast`.
+struct GroupInfo {
+    r#type: &'static str,
+    severity: &'static str,
+    description: &'static str,
+    confidence: f64,
+}
+
+/// The built-in pattern groups. A group's key is the name predicates reference
+/// via `PatternMatches`/`CategoryMatches`/`MinMatchCount`.
+fn group_info(group: &str) -> GroupInfo {
+    match group {
+        "harmful" => GroupInfo {
+            r#type: "harmful_content",
+            severity: "critical",
+            description: "Text contains potentially harmful content",
+            confidence: 0.95,
+        },
+        "bias" => GroupInfo {
+            r#type: "bias",
+            severity: "high",
+            description: "Text contains potentially biased content",
+            confidence: 0.8,
+        },
+        "privacy" => GroupInfo {
+            r#type: "privacy",
+            severity: "high",
+            description: "Text contains personally identifiable information",
+            confidence: 0.9,
+        },
+        "illegal" => GroupInfo {
+            r#type: "illegal_content",
+            severity: "critical",
+            description: "Text contains references to illegal activities",
+            confidence: 0.85,
+        },
+        _ => GroupInfo {
+            r#type: "misinformation",
+            severity: "medium",
+            description: "Text contains potential misinformation",
+            confidence: 0.75,
+        },
+    }
+}
+
 pub struct SafetyAnalyzer {
-    harmful_patterns: Vec<Regex>,
-    bias_patterns: Vec<Regex>,
-    privacy_patterns: Vec<Regex>,
-    illegal_patterns: Vec<Regex>,
-    misinformation_patterns: Vec<Regex>,
+    harmful_patterns: Vec<Pattern>,
+    bias_patterns: Vec<Pattern>,
+    privacy_patterns: Vec<Pattern>,
+    illegal_patterns: Vec<Pattern>,
+    misinformation_patterns: Vec<Pattern>,
+    /// Typed placeholder label for each privacy pattern, parallel to
+    /// `privacy_patterns`, used when producing redacted output.
+    privacy_labels: Vec<String>,
     severity_weights: HashMap<String, f64>,
+    policies: Vec<Policy>,
+    /// Per-category confidence overrides supplied by a loaded rule pack; absent
+    /// categories fall back to the built-in `group_info` confidence.
+    confidence_overrides: HashMap<String, f64>,
 }
 
 impl SafetyAnalyzer {
@@ -86,38 +472,171 @@ impl SafetyAnalyzer {
         severity_weights.insert("critical".to_string(), 1.0);
 
         Self {
-            harmful_patterns,
-            bias_patterns,
-            privacy_patterns,
-            illegal_patterns,
-            misinformation_patterns,
+            harmful_patterns: harmful_patterns.into_iter().map(Pattern::Fast).collect(),
+            bias_patterns: bias_patterns.into_iter().map(Pattern::Fast).collect(),
+            privacy_patterns: privacy_patterns.into_iter().map(Pattern::Fast).collect(),
+            illegal_patterns: illegal_patterns.into_iter().map(Pattern::Fast).collect(),
+            misinformation_patterns: misinformation_patterns.into_iter().map(Pattern::Fast).collect(),
+            privacy_labels: ["SSN", "CREDIT_CARD", "EMAIL", "PHONE", "ADDRESS"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
             severity_weights,
+            policies: Self::default_policies(),
+            confidence_overrides: HashMap::new(),
         }
     }
 
+    /// Build an analyzer from an externally loaded rule pack. Each rule's
+    /// patterns are bucketed into the pattern group named by its `category`,
+    /// and a policy is derived per category (honoring a rule `threshold` via
+    /// `MinMatchCount`). Categories not recognized by `group_info` are rejected.
+    pub fn from_pack(pack: &RulePack) -> Result<Self, String> {
+        let mut analyzer = Self::new();
+        analyzer.harmful_patterns.clear();
+        analyzer.bias_patterns.clear();
+        analyzer.privacy_patterns.clear();
+        analyzer.illegal_patterns.clear();
+        analyzer.misinformation_patterns.clear();
+        analyzer.privacy_labels.clear();
+
+        let mut policies: Vec<Policy> = Vec::new();
+        for rule in &pack.rules {
+            let compiled = rule
+                .patterns
+                .iter()
+                .map(|p| {
+                    Pattern::compile(p, rule.fancy)
+                        .map_err(|e| format!("rule {}: bad pattern {:?}: {}", rule.id, p, e))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let bucket = match rule.category.as_str() {
+                "harmful" => &mut analyzer.harmful_patterns,
+                "bias" => &mut analyzer.bias_patterns,
+                "privacy" => &mut analyzer.privacy_patterns,
+                "illegal" => &mut analyzer.illegal_patterns,
+                "misinformation" => &mut analyzer.misinformation_patterns,
+                other => return Err(format!("rule {}: unknown category {:?}", rule.id, other)),
+            };
+            let pattern_count = compiled.len();
+            bucket.extend(compiled);
+            if rule.category == "privacy" {
+                for _ in 0..pattern_count {
+                    analyzer.privacy_labels.push(rule.id.to_uppercase());
+                }
+            }
+
+            analyzer
+                .confidence_overrides
+                .insert(rule.category.clone(), rule.confidence);
+            policies.push(Policy {
+                name: rule.category.clone(),
+                predicate: match rule.threshold {
+                    Some(n) if n > 1 => Predicate::MinMatchCount {
+                        pattern: rule.category.clone(),
+                        n,
+                    },
+                    _ => Predicate::PatternMatches(rule.category.clone()),
+                },
+                severity: rule.severity.clone(),
+                description: group_info(&rule.category).description.to_string(),
+            });
+        }
+
+        analyzer.policies = policies;
+        Ok(analyzer)
+    }
+
+    /// Construct an analyzer with a caller-supplied policy set, e.g. loaded from
+    /// a config file, while reusing the built-in pattern groups.
+    pub fn with_policies(policies: Vec<Policy>) -> Self {
+        let mut analyzer = Self::new();
+        analyzer.policies = policies;
+        analyzer
+    }
+
+    /// The default policy set, one rule per built-in pattern group, which
+    /// reproduces the legacy hardcoded behavior: flag a group whenever any of
+    /// its patterns match.
+    fn default_policies() -> Vec<Policy> {
+        ["harmful", "bias", "privacy", "illegal", "misinformation"]
+            .iter()
+            .map(|group| {
+                let info = group_info(group);
+                Policy {
+                    name: group.to_string(),
+                    predicate: Predicate::PatternMatches(group.to_string()),
+                    severity: info.severity.to_string(),
+                    description: info.description.to_string(),
+                }
+            })
+            .collect()
+    }
+
     pub fn analyze(&self, text: &str, context: Option<&str>) -> SafetyResult {
+        self.analyze_with_options(text, context, &NormalizationOptions::default())
+    }
+
+    /// Same as `analyze` but lets the caller tune (or, by passing every field
+    /// `false`, effectively disable) the de-obfuscation pipeline for literal
+    /// matching.
+    pub fn analyze_with_options(
+        &self,
+        text: &str,
+        context: Option<&str>,
+        norm_opts: &NormalizationOptions,
+    ) -> SafetyResult {
         let start_time = std::time::Instant::now();
-        let text_lower = text.to_lowercase();
+        let normalized = NormalizedText::build(text, norm_opts);
         let mut violations = Vec::new();
         let mut pattern_matches = 0;
 
-        // Parallel analysis of different violation types
-        let violation_checks: Vec<Box<dyn Fn() -> Vec<Violation> + Send + Sync>> = vec![
-            Box::new(|| self.check_harmful_content(&text_lower)),
-            Box::new(|| self.check_bias(&text_lower)),
+        // Parallel gathering of match facts across the pattern groups. The
+        // content checks run against the normalized text and map matches back to
+        // the original span for evidence; privacy matching stays on the raw text
+        // so digit patterns aren't disturbed by de-obfuscation.
+        let fact_checks: Vec<Box<dyn Fn() -> Vec<(&'static str, String)> + Send + Sync>> = vec![
+            Box::new(|| self.check_harmful_content(&normalized, text)),
+            Box::new(|| self.check_bias(&normalized, text)),
             Box::new(|| self.check_privacy(text)),
-            Box::new(|| self.check_illegal_content(&text_lower)),
-            Box::new(|| self.check_misinformation(&text_lower)),
+            Box::new(|| self.check_illegal_content(&normalized, text)),
+            Box::new(|| self.check_misinformation(&normalized, text)),
         ];
 
-        let results: Vec<Vec<Violation>> = violation_checks
+        let gathered: Vec<Vec<(&'static str, String)>> = fact_checks
             .into_par_iter()
             .map(|check| check())
             .collect();
 
-        for result in results {
-            pattern_matches += result.len();
-            violations.extend(result);
+        let mut facts = MatchFacts {
+            context: context.map(|c| c.to_lowercase()),
+            ..Default::default()
+        };
+        for group_matches in gathered {
+            pattern_matches += group_matches.len();
+            for (group, evidence) in group_matches {
+                facts.record(group, evidence);
+            }
+        }
+
+        // Evaluate each policy against the accumulated facts.
+        let rank = |label: &str| self.severity_rank(label);
+        for policy in &self.policies {
+            if policy.predicate.eval(&facts, &rank) {
+                let info = group_info(&policy.name);
+                violations.push(Violation {
+                    r#type: info.r#type.to_string(),
+                    severity: policy.severity.clone(),
+                    description: policy.description.clone(),
+                    confidence: self
+                        .confidence_overrides
+                        .get(&policy.name)
+                        .copied()
+                        .unwrap_or(info.confidence),
+                    evidence: self.policy_evidence(&policy.predicate, &facts),
+                });
+            }
         }
 
         // Context-aware adjustments
@@ -143,94 +662,124 @@ impl SafetyAnalyzer {
         }
     }
 
-    fn check_harmful_content(&self, text: &str) -> Vec<Violation> {
-        let mut violations = Vec::new();
-        
+    /// Replace every privacy match with a typed placeholder (`<EMAIL>`,
+    /// `<SSN>`, …), preserving the surrounding text, and report how many of
+    /// each type were removed. Overlapping matches are resolved left-to-right.
+    pub fn redact(&self, text: &str) -> RedactionReport {
+        let mut spans: Vec<(Range<usize>, &str)> = Vec::new();
+        for (pattern, label) in self.privacy_patterns.iter().zip(self.privacy_labels.iter()) {
+            for range in pattern.match_ranges(text) {
+                spans.push((range, label));
+            }
+        }
+        spans.sort_by_key(|(range, _)| range.start);
+
+        let mut redacted = String::with_capacity(text.len());
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        let mut cursor = 0;
+        for (range, label) in spans {
+            if range.start < cursor {
+                continue; // already covered by an earlier, overlapping match
+            }
+            redacted.push_str(&text[cursor..range.start]);
+            redacted.push_str(&format!("<{}>", label));
+            *counts.entry(label.to_string()).or_insert(0) += 1;
+            cursor = range.end;
+        }
+        redacted.push_str(&text[cursor..]);
+
+        RedactionReport { redacted, counts }
+    }
+
+    fn check_harmful_content(&self, norm: &NormalizedText, original: &str) -> Vec<(&'static str, String)> {
+        let mut facts = Vec::new();
         for pattern in &self.harmful_patterns {
-            if let Some(mat) = pattern.find(text) {
-                violations.push(Violation {
-                    r#type: "harmful_content".to_string(),
-                    severity: "critical".to_string(),
-                    description: "Text contains potentially harmful content".to_string(),
-                    confidence: 0.95,
-                    evidence: vec![mat.as_str().to_string()],
-                });
+            if let Some(span) = pattern.find(&norm.text) {
+                facts.push(("harmful", norm.evidence(original, span)));
             }
         }
-        
-        violations
+        facts
     }
 
-    fn check_bias(&self, text: &str) -> Vec<Violation> {
-        let mut violations = Vec::new();
-        
+    fn check_bias(&self, norm: &NormalizedText, original: &str) -> Vec<(&'static str, String)> {
+        let mut facts = Vec::new();
         for pattern in &self.bias_patterns {
-            if let Some(mat) = pattern.find(text) {
-                violations.push(Violation {
-                    r#type: "bias".to_string(),
-                    severity: "high".to_string(),
-                    description: "Text contains potentially biased content".to_string(),
-                    confidence: 0.8,
-                    evidence: vec![mat.as_str().to_string()],
-                });
+            if let Some(span) = pattern.find(&norm.text) {
+                facts.push(("bias", norm.evidence(original, span)));
             }
         }
-        
-        violations
+        facts
     }
 
-    fn check_privacy(&self, text: &str) -> Vec<Violation> {
-        let mut violations = Vec::new();
-        
+    fn check_privacy(&self, text: &str) -> Vec<(&'static str, String)> {
+        let mut facts = Vec::new();
         for pattern in &self.privacy_patterns {
-            for _mat in pattern.find_iter(text) {
-                violations.push(Violation {
-                    r#type: "privacy".to_string(),
-                    severity: "high".to_string(),
-                    description: "Text contains personally identifiable information".to_string(),
-                    confidence: 0.9,
-                    evidence: vec!["[REDACTED]".to_string()], // Don't include actual PII
-                });
+            for _ in 0..pattern.count_matches(text) {
+                facts.push(("privacy", "[REDACTED]".to_string())); // Don't include actual PII
             }
         }
-        
-        violations
+        facts
     }
 
-    fn check_illegal_content(&self, text: &str) -> Vec<Violation> {
-        let mut violations = Vec::new();
-        
+    fn check_illegal_content(&self, norm: &NormalizedText, original: &str) -> Vec<(&'static str, String)> {
+        let mut facts = Vec::new();
         for pattern in &self.illegal_patterns {
-            if let Some(mat) = pattern.find(text) {
-                violations.push(Violation {
-                    r#type: "illegal_content".to_string(),
-                    severity: "critical".to_string(),
-                    description: "Text contains references to illegal activities".to_string(),
-                    confidence: 0.85,
-                    evidence: vec![mat.as_str().to_string()],
-                });
+            if let Some(span) = pattern.find(&norm.text) {
+                facts.push(("illegal", norm.evidence(original, span)));
             }
         }
-        
-        violations
+        facts
     }
 
-    fn check_misinformation(&self, text: &str) -> Vec<Violation> {
-        let mut violations = Vec::new();
-        
+    fn check_misinformation(&self, norm: &NormalizedText, original: &str) -> Vec<(&'static str, String)> {
+        let mut facts = Vec::new();
         for pattern in &self.misinformation_patterns {
-            if let Some(mat) = pattern.find(text) {
-                violations.push(Violation {
-                    r#type: "misinformation".to_string(),
-                    severity: "medium".to_string(),
-                    description: "Text contains potential misinformation".to_string(),
-                    confidence: 0.75,
-                    evidence: vec![mat.as_str().to_string()],
-                });
+            if let Some(span) = pattern.find(&norm.text) {
+                facts.push(("misinformation", norm.evidence(original, span)));
+            }
+        }
+        facts
+    }
+
+    /// Ordinal rank used by `Predicate::SeverityAtLeast`. Accepts either a
+    /// severity level name (`low`/`medium`/`high`/`critical`) or a pattern
+    /// group name, whose rank is that of its default severity.
+    fn severity_rank(&self, label: &str) -> u8 {
+        let severity = match label {
+            "low" | "medium" | "high" | "critical" => label,
+            group => group_info(group).severity,
+        };
+        match severity {
+            "low" => 1,
+            "medium" => 2,
+            "high" => 3,
+            "critical" => 4,
+            _ => 0,
+        }
+    }
+
+    /// Collect the evidence spans for the pattern groups referenced by a
+    /// predicate so an emitted violation quotes what actually matched.
+    fn policy_evidence(&self, predicate: &Predicate, facts: &MatchFacts) -> Vec<String> {
+        let mut evidence = Vec::new();
+        self.collect_evidence(predicate, facts, &mut evidence);
+        evidence
+    }
+
+    fn collect_evidence(&self, predicate: &Predicate, facts: &MatchFacts, out: &mut Vec<String>) {
+        match predicate {
+            Predicate::PatternMatches(group)
+            | Predicate::CategoryMatches(group)
+            | Predicate::MinMatchCount { pattern: group, .. } => {
+                out.extend(facts.evidence(group));
+            }
+            Predicate::Not(_) | Predicate::SeverityAtLeast(_) | Predicate::ContextContains(_) => {}
+            Predicate::AnyOf(preds) | Predicate::AllOf(preds) => {
+                for p in preds {
+                    self.collect_evidence(p, facts, out);
+                }
             }
         }
-        
-        violations
     }
 
     fn adjust_for_context(&self, violations: &mut Vec<Violation>, context: &str) {
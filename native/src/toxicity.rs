@@ -0,0 +1,237 @@
+// Multi-label toxicity classification via NB-SVM.
+//
+// The interpretability module used to derive concept strengths from substring
+// hits against hardcoded keyword lists, which is brittle and uncalibrated. This
+// module replaces that path with a trained Naive-Bayes-weighted logistic
+// regression — cheap to fit, accurate for short-text toxicity, and serializable
+// so a pretrained bundle can be shipped alongside the binary and loaded at
+// runtime.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The toxicity categories predicted per input, in a stable order.
+pub const LABELS: [&str; 6] = [
+    "toxic",
+    "severe_toxic",
+    "obscene",
+    "threat",
+    "insult",
+    "identity_hate",
+];
+
+/// Laplace smoothing for the log-count ratio.
+const ALPHA: f32 = 1.0;
+
+/// A fitted NB-SVM for a single binary label: the Naive-Bayes log-count ratio
+/// `r` plus the logistic-regression weights `(w, b)` over the `r`-transformed
+/// features.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LabelModel {
+    /// Log-count ratio, aligned to the shared vocabulary.
+    pub r: Vec<f32>,
+    /// Logistic-regression weights over the NB-transformed features.
+    pub w: Vec<f32>,
+    /// Logistic-regression bias.
+    pub b: f32,
+}
+
+impl LabelModel {
+    /// Predicted probability `σ(w·(r⊙x) + b)` for one sparse TF-IDF vector.
+    fn predict(&self, features: &HashMap<usize, f32>) -> f32 {
+        let mut z = self.b;
+        for (&idx, &value) in features {
+            let transformed = value * self.r.get(idx).copied().unwrap_or(0.0);
+            z += transformed * self.w.get(idx).copied().unwrap_or(0.0);
+        }
+        sigmoid(z)
+    }
+}
+
+/// A serializable multi-label toxicity bundle: a shared vocabulary with inverse
+/// document frequencies and one [`LabelModel`] per category.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ToxicityModel {
+    /// Token -> feature index.
+    pub vocabulary: HashMap<String, usize>,
+    /// Inverse document frequency, aligned to the vocabulary.
+    pub idf: Vec<f32>,
+    /// Per-label fitted models, keyed by label name.
+    pub labels: HashMap<String, LabelModel>,
+}
+
+/// A per-category toxicity score for one input.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LabelScore {
+    pub label: String,
+    pub probability: f32,
+}
+
+impl ToxicityModel {
+    /// Load a pretrained bundle from a JSON file.
+    pub fn from_file(path: &str) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read toxicity model {}: {}", path, e))?;
+        serde_json::from_str(&contents)
+            .map_err(|e| format!("invalid toxicity model: {}", e))
+    }
+
+    /// Whitespace/punctuation tokenization matching the training path.
+    fn tokenize(text: &str) -> Vec<String> {
+        text.to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|t| !t.is_empty())
+            .map(|t| t.to_string())
+            .collect()
+    }
+
+    /// Vectorize a document into a sparse TF-IDF feature map over the learned
+    /// vocabulary. Out-of-vocabulary tokens are dropped.
+    fn vectorize(&self, text: &str) -> HashMap<usize, f32> {
+        let tokens = Self::tokenize(text);
+        let mut counts: HashMap<usize, f32> = HashMap::new();
+        for token in &tokens {
+            if let Some(&idx) = self.vocabulary.get(token) {
+                *counts.entry(idx).or_insert(0.0) += 1.0;
+            }
+        }
+        for (idx, value) in counts.iter_mut() {
+            *value *= self.idf.get(*idx).copied().unwrap_or(0.0);
+        }
+        counts
+    }
+
+    /// Predict per-label probabilities for `text`, returned in [`LABELS`] order.
+    pub fn predict(&self, text: &str) -> Vec<LabelScore> {
+        let features = self.vectorize(text);
+        LABELS
+            .iter()
+            .filter_map(|label| {
+                self.labels.get(*label).map(|model| LabelScore {
+                    label: (*label).to_string(),
+                    probability: model.predict(&features),
+                })
+            })
+            .collect()
+    }
+
+    /// Fit a bundle from labeled documents. Each entry pairs a document with the
+    /// subset of [`LABELS`] that apply to it. Intended for offline training; the
+    /// runtime loads the serialized result via [`ToxicityModel::from_file`].
+    pub fn train(documents: &[(String, Vec<String>)], epochs: usize, lr: f32) -> Self {
+        let (vocabulary, idf) = Self::build_vocabulary(documents);
+
+        // TF-IDF vectorize every document once.
+        let model_shell = ToxicityModel {
+            vocabulary: vocabulary.clone(),
+            idf: idf.clone(),
+            labels: HashMap::new(),
+        };
+        let vectors: Vec<HashMap<usize, f32>> = documents
+            .iter()
+            .map(|(text, _)| model_shell.vectorize(text))
+            .collect();
+
+        let vocab_size = vocabulary.len();
+        let mut labels = HashMap::new();
+        for label in LABELS {
+            let positive: Vec<bool> = documents
+                .iter()
+                .map(|(_, tags)| tags.iter().any(|t| t == label))
+                .collect();
+            labels.insert(
+                label.to_string(),
+                Self::fit_label(vocab_size, &vectors, &positive, epochs, lr),
+            );
+        }
+
+        ToxicityModel {
+            vocabulary,
+            idf,
+            labels,
+        }
+    }
+
+    /// Build the shared vocabulary and IDF vector from the training corpus.
+    fn build_vocabulary(documents: &[(String, Vec<String>)]) -> (HashMap<String, usize>, Vec<f32>) {
+        let mut vocabulary = HashMap::new();
+        let mut document_freq: Vec<f32> = Vec::new();
+        for (text, _) in documents {
+            let mut seen = std::collections::HashSet::new();
+            for token in Self::tokenize(text) {
+                let idx = *vocabulary.entry(token).or_insert_with(|| {
+                    document_freq.push(0.0);
+                    document_freq.len() - 1
+                });
+                if seen.insert(idx) {
+                    document_freq[idx] += 1.0;
+                }
+            }
+        }
+
+        let n = documents.len() as f32;
+        let idf = document_freq
+            .iter()
+            .map(|&df| ((1.0 + n) / (1.0 + df)).ln() + 1.0)
+            .collect();
+        (vocabulary, idf)
+    }
+
+    /// Compute the NB log-count ratio `r` for one label, then fit logistic
+    /// regression over the `r`-transformed features with batch gradient descent.
+    fn fit_label(
+        vocab_size: usize,
+        vectors: &[HashMap<usize, f32>],
+        positive: &[bool],
+        epochs: usize,
+        lr: f32,
+    ) -> LabelModel {
+        // p = α + Σ x over positive docs; q = α + Σ x over negative docs.
+        let mut p = vec![ALPHA; vocab_size];
+        let mut q = vec![ALPHA; vocab_size];
+        for (vector, &is_pos) in vectors.iter().zip(positive) {
+            let target = if is_pos { &mut p } else { &mut q };
+            for (&idx, &value) in vector {
+                target[idx] += value;
+            }
+        }
+
+        let p_norm: f32 = p.iter().sum();
+        let q_norm: f32 = q.iter().sum();
+        let r: Vec<f32> = p
+            .iter()
+            .zip(&q)
+            .map(|(&pi, &qi)| ((pi / p_norm) / (qi / q_norm)).ln())
+            .collect();
+
+        // Logistic regression on x̃ = r ⊙ x.
+        let mut w = vec![0.0f32; vocab_size];
+        let mut b = 0.0f32;
+        let scale = lr / vectors.len().max(1) as f32;
+        for _ in 0..epochs {
+            let mut grad_w = vec![0.0f32; vocab_size];
+            let mut grad_b = 0.0f32;
+            for (vector, &is_pos) in vectors.iter().zip(positive) {
+                let mut z = b;
+                for (&idx, &value) in vector {
+                    z += value * r[idx] * w[idx];
+                }
+                let error = sigmoid(z) - if is_pos { 1.0 } else { 0.0 };
+                for (&idx, &value) in vector {
+                    grad_w[idx] += error * value * r[idx];
+                }
+                grad_b += error;
+            }
+            for (wi, gi) in w.iter_mut().zip(&grad_w) {
+                *wi -= scale * gi;
+            }
+            b -= scale * grad_b;
+        }
+
+        LabelModel { r, w, b }
+    }
+}
+
+fn sigmoid(z: f32) -> f32 {
+    1.0 / (1.0 + (-z).exp())
+}